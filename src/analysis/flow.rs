@@ -0,0 +1,169 @@
+//!
+//! Time series utilities for the decoded [`ScoreFlowEntry`] and [`LeagueFlowEntry`] points.
+
+use serde::Serialize;
+
+use crate::models::{
+    common::{APIfloat, APIint},
+    labs::{leagueflow::LeagueFlowEntry, scoreflow::ScoreFlowEntry},
+};
+
+/// The length of a day, in milliseconds.
+pub const DAY_MILLIS: APIint = 86_400_000;
+
+/// A point of a time series.
+pub trait FlowEntry {
+    /// The time of the point, in milliseconds since the UNIX epoch.
+    fn ts(&self) -> APIint;
+}
+
+impl FlowEntry for ScoreFlowEntry {
+    fn ts(&self) -> APIint {
+        self.ts
+    }
+}
+
+impl FlowEntry for LeagueFlowEntry {
+    fn ts(&self) -> APIint {
+        self.ts
+    }
+}
+
+/// The points of a single UTC day.
+#[derive(Debug, Clone, Serialize)]
+pub struct DailyBucket<'a, T> {
+    /* The day, in days since the UNIX epoch. */
+    pub day: APIint,
+    pub entries: Vec<&'a T>,
+}
+
+impl<T> DailyBucket<'_, T> {
+    /// The start of the day, in milliseconds since the UNIX epoch.
+    pub fn start(&self) -> APIint {
+        self.day * DAY_MILLIS
+    }
+}
+
+/// Groups points by UTC day. Only days with at least one point are returned, in chronological order.
+pub fn daily_buckets<T: FlowEntry>(entries: &[T]) -> Vec<DailyBucket<'_, T>> {
+    let mut sorted = entries.iter().collect::<Vec<_>>();
+    sorted.sort_by_key(|entry| entry.ts());
+
+    let mut buckets: Vec<DailyBucket<'_, T>> = vec![];
+    for entry in sorted {
+        let day = entry.ts().div_euclid(DAY_MILLIS);
+
+        match buckets.last_mut() {
+            Some(bucket) if bucket.day == day => bucket.entries.push(entry),
+            _ => buckets.push(DailyBucket { day, entries: vec![entry] }),
+        }
+    }
+
+    buckets
+}
+
+/// The average of every window of `window` consecutive values.
+/// The first average covers the values `0..window`, so there are `values.len() - window + 1` of them.
+pub fn rolling_average(values: &[APIfloat], window: usize) -> Vec<APIfloat> {
+    if window == 0 {
+        return vec![];
+    }
+
+    values
+        .windows(window)
+        .map(|values| values.iter().sum::<APIfloat>() / window as APIfloat)
+        .collect()
+}
+
+/// A run of consecutive victories or defeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Streak {
+    /* The index of the first match of the streak. */
+    pub start: usize,
+    /* The amount of matches in the streak. */
+    pub len: usize,
+    pub won: bool,
+}
+
+/// Every streak of victories or defeats, in order.
+/// Results which are neither a victory nor a defeat (ties, no contests...) end the current streak.
+pub fn streaks(entries: &[LeagueFlowEntry]) -> Vec<Streak> {
+    let mut streaks: Vec<Streak> = vec![];
+    let mut current: Option<Streak> = None;
+
+    for (index, entry) in entries.iter().enumerate() {
+        let won = match (entry.result.is_victory(), entry.result.is_defeat()) {
+            (true, _) => Some(true),
+            (_, true) => Some(false),
+            _ => None,
+        };
+
+        current = match (current, won) {
+            (Some(mut streak), Some(won)) if streak.won == won => {
+                streak.len += 1;
+                Some(streak)
+            }
+            (previous, won) => {
+                streaks.extend(previous);
+                won.map(|won| Streak { start: index, len: 1, won })
+            }
+        };
+    }
+    streaks.extend(current);
+
+    streaks
+}
+
+/// The longest streak of victories, the earliest one in case of a tie.
+pub fn longest_win_streak(entries: &[LeagueFlowEntry]) -> Option<Streak> {
+    longest_streak(entries, true)
+}
+
+/// The longest streak of defeats, the earliest one in case of a tie.
+pub fn longest_loss_streak(entries: &[LeagueFlowEntry]) -> Option<Streak> {
+    longest_streak(entries, false)
+}
+
+fn longest_streak(entries: &[LeagueFlowEntry], won: bool) -> Option<Streak> {
+    streaks(entries)
+        .into_iter()
+        .filter(|streak| streak.won == won)
+        .fold(None, |longest: Option<Streak>, streak| match longest {
+            Some(longest) if longest.len >= streak.len => Some(longest),
+            _ => Some(streak),
+        })
+}
+
+/// The TR won or lost over a range of consecutive matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stretch {
+    /* The index of the first match of the stretch. */
+    pub start: usize,
+    /* The index of the last match of the stretch. */
+    pub end: usize,
+    pub tr_delta: APIint,
+}
+
+/// The `games` consecutive matches during which the most TR was won.
+/// The TR before the first match is unknown, so stretches start at the second match at the earliest.
+pub fn best_stretch(entries: &[LeagueFlowEntry], games: usize) -> Option<Stretch> {
+    stretches(entries, games).max_by_key(|stretch| (stretch.tr_delta, std::cmp::Reverse(stretch.start)))
+}
+
+/// The `games` consecutive matches during which the most TR was lost.
+/// The TR before the first match is unknown, so stretches start at the second match at the earliest.
+pub fn worst_stretch(entries: &[LeagueFlowEntry], games: usize) -> Option<Stretch> {
+    stretches(entries, games).min_by_key(|stretch| (stretch.tr_delta, stretch.start))
+}
+
+fn stretches(entries: &[LeagueFlowEntry], games: usize) -> impl Iterator<Item = Stretch> + '_ {
+    entries
+        .windows(games + 1)
+        .enumerate()
+        .filter(move |_| games > 0)
+        .map(move |(index, window)| Stretch {
+            start: index + 1,
+            end: index + games,
+            tr_delta: window[games].tr - window[0].tr,
+        })
+}
@@ -0,0 +1,272 @@
+//!
+//! A typed recap of a TETRA LEAGUE match, built from a [`LeagueRecord`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    math::stats::{DerivedStats, VersusStats},
+    models::{
+        common::{APIfloat, APIint, APIstring, APItimestamp},
+        users::{
+            user_rank::UserRank,
+            user_records::{
+                league_record::{LeagueExtrasData, LeagueRound},
+                LeagueRecord,
+            },
+        },
+    },
+};
+
+/// A player's stats for a single round.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoundPlayer {
+    /* The player's User ID. */
+    pub id: APIstring,
+    /* The player's username. */
+    pub username: APIstring,
+    /* Whether the player made it through the round alive. */
+    pub alive: bool,
+    /* The time alive in this round, in milliseconds. */
+    pub lifetime: APIint,
+    pub apm: APIfloat,
+    pub pps: APIfloat,
+    pub vs: APIfloat,
+}
+
+/// A single round of the match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchRound {
+    /* The zero-indexed position of the round in the match. */
+    pub index: usize,
+    /* The User ID of the player who won the round, if it could be determined. */
+    pub winner: Option<APIstring>,
+    /* How long the round lasted, in milliseconds. */
+    pub duration: APIint,
+    pub players: Vec<RoundPlayer>,
+}
+
+/// A player's rating data right before or right after the match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerRating {
+    pub tr: APIfloat,
+    pub glicko: APIfloat,
+    pub rd: APIfloat,
+    pub rank: UserRank,
+    pub placement: Option<APIfloat>,
+}
+
+impl From<&LeagueExtrasData> for PlayerRating {
+    fn from(value: &LeagueExtrasData) -> Self {
+        Self {
+            tr: value.tr,
+            glicko: value.glicko,
+            rd: value.rd,
+            rank: value.rank.clone(),
+            placement: value.placement,
+        }
+    }
+}
+
+/// A player's recap of the whole match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchPlayer {
+    /* The player's User ID. */
+    pub id: APIstring,
+    /* The player's username. */
+    pub username: APIstring,
+    /* Whether the player was still in the game at the end. If false, the user has likely been disqualified. */
+    pub active: bool,
+    /* The amount of rounds won by the player. */
+    pub wins: APIint,
+    /* The average APM across all rounds. */
+    pub apm: Option<APIfloat>,
+    /* The average PPS across all rounds. */
+    pub pps: Option<APIfloat>,
+    /* The average VS across all rounds. */
+    pub vs: Option<APIfloat>,
+    /* The player's stats for every round they took part in. */
+    pub rounds: Vec<RoundPlayer>,
+    /* The player's rating before the match, if known. */
+    pub before: Option<PlayerRating>,
+    /* The player's rating after the match, if known. */
+    pub after: Option<PlayerRating>,
+}
+
+impl MatchPlayer {
+    /// The TR won (positive) or lost (negative) during the match.
+    pub fn tr_delta(&self) -> Option<APIfloat> {
+        Some(self.after.as_ref()?.tr - self.before.as_ref()?.tr)
+    }
+
+    /// The Glicko-2 rating won (positive) or lost (negative) during the match.
+    pub fn glicko_delta(&self) -> Option<APIfloat> {
+        Some(self.after.as_ref()?.glicko - self.before.as_ref()?.glicko)
+    }
+
+    /// Whether the player's rank changed during the match.
+    pub fn rank_changed(&self) -> bool {
+        match (&self.before, &self.after) {
+            (Some(before), Some(after)) => before.rank != after.rank,
+            _ => false,
+        }
+    }
+}
+
+impl VersusStats for MatchPlayer {
+    fn apm(&self) -> Option<APIfloat> {
+        self.apm
+    }
+
+    fn pps(&self) -> Option<APIfloat> {
+        self.pps
+    }
+
+    fn vs(&self) -> Option<APIfloat> {
+        self.vs
+    }
+}
+
+/// A recap of a TETRA LEAGUE match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchSummary {
+    /* The ID of the record. */
+    pub id: APIstring,
+    /* The ID of the replay. */
+    pub replayid: APIstring,
+    /* The time the match was played at. */
+    pub ts: APItimestamp,
+    /* The result of the match from the point of view of the record's owner (e.g. "victory"). */
+    pub result: APIstring,
+    /* The players, ordered like the final leaderboard. */
+    pub players: Vec<MatchPlayer>,
+    pub rounds: Vec<MatchRound>,
+}
+
+impl MatchSummary {
+    pub fn from_record(record: &LeagueRecord) -> Self {
+        let rounds = record
+            .results
+            .rounds
+            .iter()
+            .enumerate()
+            .map(|(index, round)| MatchRound {
+                index,
+                winner: round_winner(round),
+                duration: round.iter().map(|player| player.lifetime).max().unwrap_or(0),
+                players: round.iter().map(round_player).collect(),
+            })
+            .collect::<Vec<_>>();
+
+        let players = record
+            .results
+            .leaderboard
+            .iter()
+            .map(|player| {
+                let ratings = record.extras.league.get(&player.id);
+                let rating = |index: usize| {
+                    ratings
+                        .and_then(|ratings| ratings.get(index))
+                        .and_then(|rating| rating.as_ref())
+                        .map(PlayerRating::from)
+                };
+                let player_rounds = rounds
+                    .iter()
+                    .filter_map(|round| round.players.iter().find(|round_player| round_player.id == player.id))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                MatchPlayer {
+                    id: player.id.clone(),
+                    username: player.username.clone(),
+                    active: player.active,
+                    wins: player.wins,
+                    apm: player.stats.apm.or_else(|| average(&player_rounds, |round| round.apm)),
+                    pps: player.stats.pps.or_else(|| average(&player_rounds, |round| round.pps)),
+                    vs: player.stats.vsscore.or_else(|| average(&player_rounds, |round| round.vs)),
+                    rounds: player_rounds,
+                    before: rating(0),
+                    after: rating(1),
+                }
+            })
+            .collect();
+
+        Self {
+            id: record.id.clone(),
+            replayid: record.replayid.clone(),
+            ts: record.ts.clone(),
+            result: record.extras.result.clone(),
+            players,
+            rounds,
+        }
+    }
+
+    /// The final score, ordered like the players (e.g. "7–5").
+    pub fn score_line(&self) -> String {
+        self.players
+            .iter()
+            .map(|player| player.wins.to_string())
+            .collect::<Vec<_>>()
+            .join("–")
+    }
+
+    /// The player who won the most rounds, or None in case of a tie.
+    pub fn winner(&self) -> Option<&MatchPlayer> {
+        let best = self.players.iter().max_by_key(|player| player.wins)?;
+
+        match self.players.iter().filter(|player| player.wins == best.wins).count() {
+            1 => Some(best),
+            _ => None,
+        }
+    }
+
+    /// Finds a player by User ID.
+    pub fn player(&self, id: &str) -> Option<&MatchPlayer> {
+        self.players.iter().find(|player| player.id == id)
+    }
+
+    /// The derived stats of every player, in the same order as [`MatchSummary::players`].
+    pub fn derived_stats(&self) -> Vec<Option<DerivedStats>> {
+        self.players.iter().map(|player| player.derived_stats()).collect()
+    }
+}
+
+impl From<&LeagueRecord> for MatchSummary {
+    fn from(value: &LeagueRecord) -> Self {
+        Self::from_record(value)
+    }
+}
+
+fn round_player(player: &LeagueRound) -> RoundPlayer {
+    RoundPlayer {
+        id: player.id.clone(),
+        username: player.username.clone(),
+        alive: player.alive,
+        lifetime: player.lifetime,
+        apm: player.stats.apm,
+        pps: player.stats.pps,
+        vs: player.stats.vsscore,
+    }
+}
+
+fn average(rounds: &[RoundPlayer], value: impl Fn(&RoundPlayer) -> APIfloat) -> Option<APIfloat> {
+    if rounds.is_empty() {
+        return None;
+    }
+
+    Some(rounds.iter().map(value).sum::<APIfloat>() / rounds.len() as APIfloat)
+}
+
+fn round_winner(round: &[LeagueRound]) -> Option<APIstring> {
+    let mut alive = round.iter().filter(|player| player.alive);
+
+    match (alive.next(), alive.next()) {
+        (Some(winner), None) => Some(winner.id.clone()),
+        // Nobody (or everybody) survived, fall back to whoever lasted the longest.
+        _ => {
+            let longest = round.iter().max_by_key(|player| player.lifetime)?;
+            let ties = round.iter().filter(|player| player.lifetime == longest.lifetime).count();
+
+            (ties == 1).then(|| longest.id.clone())
+        }
+    }
+}
@@ -0,0 +1,10 @@
+/// TETRA LEAGUE match recaps built from a [`LeagueRecord`](crate::models::users::user_records::LeagueRecord).
+pub mod league_match;
+/// QUICK PLAY run breakdowns built from zenith and zenithex records.
+pub mod zenith;
+/// 40 LINES and BLITZ run breakdowns built from sprint and blitz records.
+pub mod solo;
+/// Time series utilities for scoreflow and leagueflow data.
+pub mod flow;
+/// TETRA LEAGUE timelines merging past seasons with the current one.
+pub mod season_history;
@@ -0,0 +1,182 @@
+//!
+//! A user's TETRA LEAGUE timeline across seasons, built from a [`LeagueSummary`] and its past seasons.
+
+use serde::Serialize;
+
+use crate::{
+    math::stats::VersusStats,
+    models::{
+        common::{APIfloat, APIint, APIstring},
+        season::Season,
+        users::{
+            summaries::tetra_league::{LeagueSummary, LeagueSummaryPast},
+            user_rank::UserRank,
+        },
+    },
+};
+
+/// A user's standing at the end of a season, or right now for the current season.
+#[derive(Debug, Clone, Serialize)]
+pub struct SeasonEntry {
+    pub season: Season,
+    /* Whether the season is still running. */
+    pub current: bool,
+    /* The username the user had at the time, only known for past seasons. */
+    pub username: Option<APIstring>,
+    /* The country the user represented at the time, only known for past seasons. */
+    pub country: Option<APIstring>,
+    pub ranked: bool,
+    pub rank: Option<UserRank>,
+    pub bestrank: Option<UserRank>,
+    pub tr: Option<APIfloat>,
+    pub glicko: Option<APIfloat>,
+    pub rd: Option<APIfloat>,
+    pub gxe: Option<APIfloat>,
+    /* The position in the season's global leaderboard. */
+    pub placement: Option<APIint>,
+    pub gamesplayed: Option<APIint>,
+    pub gameswon: Option<APIint>,
+    pub apm: Option<APIfloat>,
+    pub pps: Option<APIfloat>,
+    pub vs: Option<APIfloat>,
+}
+
+impl SeasonEntry {
+    pub fn from_past(past: &LeagueSummaryPast) -> Self {
+        Self {
+            season: past.season.clone(),
+            current: false,
+            username: Some(past.username.clone()),
+            country: past.country.clone(),
+            ranked: past.ranked,
+            rank: Some(past.rank.clone()),
+            bestrank: past.bestrank.clone(),
+            tr: past.tr,
+            glicko: past.glicko,
+            rd: past.rd,
+            gxe: past.gxe,
+            placement: past.placement,
+            gamesplayed: past.gamesplayed,
+            gameswon: past.gameswon,
+            apm: Some(past.apm),
+            pps: Some(past.pps),
+            vs: Some(past.vs),
+        }
+    }
+
+    /// The current season's entry. The API uses -1 for values which do not apply, they are mapped to None.
+    pub fn from_current(season: Season, summary: &LeagueSummary) -> Self {
+        let known = |value: Option<APIfloat>| value.filter(|value| *value >= 0.0);
+
+        Self {
+            season,
+            current: true,
+            username: None,
+            country: None,
+            ranked: summary.rank.as_ref().is_some_and(UserRank::is_ranked),
+            rank: summary.rank.clone(),
+            bestrank: summary.bestrank.clone(),
+            tr: known(summary.tr),
+            glicko: known(summary.glicko),
+            rd: known(summary.rd),
+            gxe: known(summary.gxe),
+            placement: summary.standing.filter(|standing| *standing > 0),
+            gamesplayed: summary.gamesplayed,
+            gameswon: summary.gameswon,
+            apm: summary.apm,
+            pps: summary.pps,
+            vs: summary.vs,
+        }
+    }
+
+    /// The share (0~1) of games won.
+    pub fn winrate(&self) -> Option<APIfloat> {
+        let played = self.gamesplayed.filter(|played| *played > 0)?;
+
+        Some(self.gameswon? as APIfloat / played as APIfloat)
+    }
+}
+
+impl VersusStats for SeasonEntry {
+    fn apm(&self) -> Option<APIfloat> {
+        self.apm
+    }
+
+    fn pps(&self) -> Option<APIfloat> {
+        self.pps
+    }
+
+    fn vs(&self) -> Option<APIfloat> {
+        self.vs
+    }
+}
+
+/// Every season a user played, from the oldest to the newest.
+#[derive(Debug, Clone, Serialize)]
+pub struct SeasonHistory {
+    pub entries: Vec<SeasonEntry>,
+}
+
+impl SeasonHistory {
+    /// Merges the past seasons of a summary with the current one.
+    /// The API does not tell which season is running, so it has to be given, see [`Season::latest_known`].
+    /// The current season is skipped if the user has not played any game in it.
+    pub fn from_summary(summary: &LeagueSummary, current_season: Season) -> Self {
+        let mut entries = summary
+            .past
+            .iter()
+            .flatten()
+            .filter(|(season, _)| **season != current_season)
+            .map(|(_, past)| SeasonEntry::from_past(past))
+            .collect::<Vec<_>>();
+        entries.sort_by(|left, right| left.season.cmp(&right.season));
+
+        if summary.gamesplayed.is_some_and(|played| played > 0) {
+            entries.push(SeasonEntry::from_current(current_season, summary));
+        }
+
+        Self { entries }
+    }
+
+    pub fn current(&self) -> Option<&SeasonEntry> {
+        self.entries.iter().find(|entry| entry.current)
+    }
+
+    pub fn past(&self) -> impl Iterator<Item = &SeasonEntry> {
+        self.entries.iter().filter(|entry| !entry.current)
+    }
+
+    pub fn season(&self, season: &Season) -> Option<&SeasonEntry> {
+        self.entries.iter().find(|entry| &entry.season == season)
+    }
+
+    /// The season the user finished with the highest TR.
+    pub fn best_by_tr(&self) -> Option<&SeasonEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.tr.is_some())
+            .max_by(|left, right| left.tr.partial_cmp(&right.tr).unwrap_or(std::cmp::Ordering::Equal))
+    }
+
+    /// The best placement reached in any season.
+    pub fn best_placement(&self) -> Option<&SeasonEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.placement.is_some())
+            .min_by_key(|entry| entry.placement)
+    }
+
+    /// The highest rank reached in any season, best ranks included.
+    pub fn peak_rank(&self) -> Option<UserRank> {
+        self.entries
+            .iter()
+            .flat_map(|entry| [entry.rank.clone(), entry.bestrank.clone()])
+            .flatten()
+            .max()
+    }
+
+    /// The total amount of games played across all seasons.
+    pub fn total_games(&self) -> APIint {
+        self.entries.iter().filter_map(|entry| entry.gamesplayed).sum()
+    }
+}
@@ -0,0 +1,281 @@
+//!
+//! A typed breakdown of a 40 LINES or BLITZ run, built from a [`SprintRecord`] or a [`BlitzRecord`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    http::parameters::personal_user_records::GameMode,
+    models::{
+        common::{APIfloat, APIint, APIstring},
+        users::user_records::{blitz_record::BlitzClears, sprint_record::SprintClears, BlitzRecord, SprintRecord},
+    },
+};
+
+/// Formats a duration in milliseconds as `mm:ss.fff`.
+pub fn format_time(milliseconds: APIfloat) -> String {
+    let total = milliseconds.max(0.0).round() as u64;
+
+    format!("{:02}:{:02}.{:03}", total / 60_000, total / 1000 % 60, total % 1000)
+}
+
+/// How many times each kind of line clear happened during a run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineClears {
+    pub singles: APIint,
+    pub doubles: APIint,
+    pub triples: APIint,
+    pub quads: APIint,
+    pub tspin_singles: APIint,
+    pub tspin_doubles: APIint,
+    pub tspin_triples: APIint,
+    pub tspin_quads: APIint,
+    pub mini_tspin_singles: APIint,
+    pub mini_tspin_doubles: APIint,
+    /* T-spins which did not clear any line. */
+    pub tspins_no_clear: APIint,
+    pub all_clears: APIint,
+}
+
+impl LineClears {
+    /// The amount of line clears which were T-spins (including minis).
+    pub fn tspin_clears(&self) -> APIint {
+        self.tspin_singles + self.tspin_doubles + self.tspin_triples + self.tspin_quads + self.mini_tspin_singles + self.mini_tspin_doubles
+    }
+
+    /// The amount of actions which cleared at least one line.
+    pub fn total_clears(&self) -> APIint {
+        self.singles + self.doubles + self.triples + self.quads + self.tspin_clears()
+    }
+
+    /// The share (0~1) of line clears which were T-spins, or None if no line was cleared.
+    pub fn tspin_share(&self) -> Option<APIfloat> {
+        let total = self.total_clears();
+
+        (total > 0).then(|| self.tspin_clears() as APIfloat / total as APIfloat)
+    }
+}
+
+impl From<&SprintClears> for LineClears {
+    fn from(value: &SprintClears) -> Self {
+        Self {
+            singles: value.singles.into(),
+            doubles: value.doubles.into(),
+            triples: value.triples.into(),
+            quads: value.quads.into(),
+            tspin_singles: value.tspinsingles.into(),
+            tspin_doubles: value.tspindoubles.into(),
+            tspin_triples: value.tspintriples.into(),
+            tspin_quads: value.tspinquads.into(),
+            mini_tspin_singles: value.minitspinsingles.into(),
+            mini_tspin_doubles: value.minitspindoubles.into(),
+            tspins_no_clear: (value.realtspins + value.minitspins).into(),
+            all_clears: value.allclear.into(),
+        }
+    }
+}
+
+impl From<&BlitzClears> for LineClears {
+    fn from(value: &BlitzClears) -> Self {
+        Self {
+            singles: value.singles.into(),
+            doubles: value.doubles.into(),
+            triples: value.triples.into(),
+            quads: value.quads.into(),
+            tspin_singles: value.tspinsingles.into(),
+            tspin_doubles: value.tspindoubles.into(),
+            tspin_triples: value.tspintriples.into(),
+            tspin_quads: value.tspinquads.into(),
+            mini_tspin_singles: value.minitspinsingles.into(),
+            mini_tspin_doubles: value.minitspindoubles.into(),
+            tspins_no_clear: (value.realtspins + value.minitspins).into(),
+            all_clears: value.allclear.into(),
+        }
+    }
+}
+
+/// The level reached at the end of a run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LevelProgress {
+    pub level: APIint,
+    /* The lines cleared since the current level was reached. */
+    pub lines: APIint,
+    /* The lines needed to reach the next level. */
+    pub lines_needed: APIint,
+}
+
+impl LevelProgress {
+    /// The progress (0~1) towards the next level.
+    pub fn progress(&self) -> APIfloat {
+        if self.lines_needed <= 0 {
+            return 0.0;
+        }
+
+        (self.lines as APIfloat / self.lines_needed as APIfloat).clamp(0.0, 1.0)
+    }
+}
+
+/// A single statistic of two runs put side by side, see [`SoloRun::compare`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatComparison {
+    pub stat: APIstring,
+    pub first: Option<APIfloat>,
+    pub second: Option<APIfloat>,
+}
+
+impl StatComparison {
+    /// The difference between the second and the first run.
+    pub fn delta(&self) -> Option<APIfloat> {
+        Some(self.second? - self.first?)
+    }
+}
+
+type RunStat = fn(&SoloRun) -> Option<APIfloat>;
+
+/// A breakdown of a 40 LINES or BLITZ run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoloRun {
+    /* The ID of the record. */
+    pub id: APIstring,
+    pub gamemode: GameMode,
+    /* The duration of the run, in milliseconds. */
+    pub finaltime: APIfloat,
+    pub score: APIint,
+    pub lines: APIint,
+    pub pieces: APIint,
+    pub inputs: APIint,
+    pub holds: Option<APIint>,
+    /* The amount of pieces placed with perfect finesse, if known. */
+    pub perfect_pieces: Option<APIint>,
+    /* The amount of finesse faults, if known. */
+    pub finesse_faults: Option<APIint>,
+    pub clears: LineClears,
+    pub level: LevelProgress,
+    pub top_combo: APIint,
+    pub top_btb: APIint,
+}
+
+impl SoloRun {
+    pub fn from_sprint_record(record: &SprintRecord) -> Self {
+        let stats = &record.results.stats;
+
+        Self {
+            id: record.id.clone(),
+            gamemode: record.gamemode,
+            finaltime: stats.finaltime,
+            score: stats.score,
+            lines: stats.lines,
+            pieces: stats.piecesplaced,
+            inputs: stats.inputs,
+            holds: stats.holds,
+            perfect_pieces: stats.finesse.as_ref().map(|finesse| finesse.perfectpieces.into()),
+            finesse_faults: stats.finesse.as_ref().map(|finesse| finesse.faults.into()),
+            clears: LineClears::from(&stats.clears),
+            level: LevelProgress {
+                level: stats.level,
+                lines: stats.level_lines,
+                lines_needed: stats.level_lines_needed,
+            },
+            top_combo: stats.topcombo,
+            top_btb: stats.topbtb,
+        }
+    }
+
+    pub fn from_blitz_record(record: &BlitzRecord) -> Self {
+        let stats = &record.results.stats;
+
+        Self {
+            id: record.id.clone(),
+            gamemode: record.gamemode,
+            finaltime: stats.finaltime,
+            score: stats.score,
+            lines: stats.lines,
+            pieces: stats.piecesplaced,
+            inputs: stats.inputs,
+            holds: stats.holds,
+            perfect_pieces: stats.finesse.as_ref().map(|finesse| finesse.perfectpieces.into()),
+            finesse_faults: stats.finesse.as_ref().map(|finesse| finesse.faults.into()),
+            clears: LineClears::from(&stats.clears),
+            level: LevelProgress {
+                level: stats.level,
+                lines: stats.level_lines,
+                lines_needed: stats.level_lines_needed,
+            },
+            top_combo: stats.topcombo,
+            top_btb: stats.topbtb,
+        }
+    }
+
+    /// The duration of the run formatted as `mm:ss.fff`.
+    pub fn formatted_time(&self) -> String {
+        format_time(self.finaltime)
+    }
+
+    /// Pieces per second.
+    pub fn pps(&self) -> Option<APIfloat> {
+        (self.finaltime > 0.0).then(|| self.pieces as APIfloat / (self.finaltime / 1000.0))
+    }
+
+    /// Keys (inputs) per piece.
+    pub fn kpp(&self) -> Option<APIfloat> {
+        (self.pieces > 0).then(|| self.inputs as APIfloat / self.pieces as APIfloat)
+    }
+
+    /// Keys (inputs) per second.
+    pub fn kps(&self) -> Option<APIfloat> {
+        (self.finaltime > 0.0).then(|| self.inputs as APIfloat / (self.finaltime / 1000.0))
+    }
+
+    /// The share (0~1) of pieces placed with perfect finesse.
+    pub fn finesse_rate(&self) -> Option<APIfloat> {
+        let perfect = self.perfect_pieces?;
+
+        (self.pieces > 0).then(|| perfect as APIfloat / self.pieces as APIfloat)
+    }
+
+    /// The share (0~1) of line clears which were T-spins.
+    pub fn tspin_share(&self) -> Option<APIfloat> {
+        self.clears.tspin_share()
+    }
+
+    /// The average score earned per piece placed.
+    pub fn score_per_piece(&self) -> Option<APIfloat> {
+        (self.pieces > 0).then(|| self.score as APIfloat / self.pieces as APIfloat)
+    }
+
+    /// Puts the main statistics of two runs side by side.
+    pub fn compare(&self, other: &SoloRun) -> Vec<StatComparison> {
+        let stats: [(&str, RunStat); 10] = [
+            ("time", |run| Some(run.finaltime)),
+            ("score", |run| Some(run.score as APIfloat)),
+            ("pieces", |run| Some(run.pieces as APIfloat)),
+            ("pps", SoloRun::pps),
+            ("kpp", SoloRun::kpp),
+            ("kps", SoloRun::kps),
+            ("finesse", SoloRun::finesse_rate),
+            ("tspin share", SoloRun::tspin_share),
+            ("score per piece", SoloRun::score_per_piece),
+            ("level", |run| Some(run.level.level as APIfloat)),
+        ];
+
+        stats
+            .iter()
+            .map(|(stat, value)| StatComparison {
+                stat: stat.to_string(),
+                first: value(self),
+                second: value(other),
+            })
+            .collect()
+    }
+}
+
+impl From<&SprintRecord> for SoloRun {
+    fn from(value: &SprintRecord) -> Self {
+        Self::from_sprint_record(value)
+    }
+}
+
+impl From<&BlitzRecord> for SoloRun {
+    fn from(value: &BlitzRecord) -> Self {
+        Self::from_blitz_record(value)
+    }
+}
@@ -0,0 +1,248 @@
+//!
+//! A typed breakdown of a QUICK PLAY climb, built from a [`ZenithRecord`] or a [`ZenithExRecord`].
+
+use serde::Serialize;
+
+use crate::models::{
+    common::{APIfloat, APIint, APIstring},
+    users::user_records::{zenith_record::ZenithMod, ZenithExRecord, ZenithRecord},
+};
+
+/// A floor of the QUICK PLAY tower.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ZenithFloor {
+    /* The floor number, starting at 1. */
+    pub number: u8,
+    /* The name of the floor. */
+    pub name: &'static str,
+    /* The altitude at which the floor starts, in meters. */
+    pub altitude: APIfloat,
+}
+
+/// Every floor of the QUICK PLAY tower, from the bottom to the top.
+pub const FLOORS: [ZenithFloor; 10] = [
+    ZenithFloor { number: 1, name: "Hall of Beginnings", altitude: 0.0 },
+    ZenithFloor { number: 2, name: "The Hotel", altitude: 50.0 },
+    ZenithFloor { number: 3, name: "The Casino", altitude: 150.0 },
+    ZenithFloor { number: 4, name: "The Arena", altitude: 300.0 },
+    ZenithFloor { number: 5, name: "The Museum", altitude: 450.0 },
+    ZenithFloor { number: 6, name: "Abandoned Offices", altitude: 650.0 },
+    ZenithFloor { number: 7, name: "The Laboratory", altitude: 850.0 },
+    ZenithFloor { number: 8, name: "The Core", altitude: 1100.0 },
+    ZenithFloor { number: 9, name: "Corruption", altitude: 1350.0 },
+    ZenithFloor { number: 10, name: "Platform of the Gods", altitude: 1650.0 },
+];
+
+impl ZenithFloor {
+    /// Finds a floor by its number (1 to 10).
+    pub fn from_number(number: APIint) -> Option<ZenithFloor> {
+        FLOORS.iter().find(|floor| floor.number as APIint == number).copied()
+    }
+
+    /// Finds the floor a given altitude is part of.
+    pub fn from_altitude(altitude: APIfloat) -> ZenithFloor {
+        FLOORS.iter().rev().find(|floor| altitude >= floor.altitude).copied().unwrap_or(FLOORS[0])
+    }
+}
+
+/// The time spent reaching and climbing a floor.
+#[derive(Debug, Clone, Serialize)]
+pub struct FloorSplit {
+    pub floor: ZenithFloor,
+    /* The time at which the floor was reached, in milliseconds since the start of the run. */
+    pub reached_at: APIint,
+    /* The time spent on the previous floor before reaching this one, in milliseconds. */
+    pub time_on_previous_floor: APIint,
+}
+
+/// The difference between two climbs on a given floor, see [`ZenithRun::compare`].
+#[derive(Debug, Clone, Serialize)]
+pub struct FloorComparison {
+    pub floor: ZenithFloor,
+    /* When the first run reached the floor, in milliseconds. */
+    pub first: Option<APIint>,
+    /* When the second run reached the floor, in milliseconds. */
+    pub second: Option<APIint>,
+}
+
+impl FloorComparison {
+    /// How much later (positive) or earlier (negative) the second run reached the floor, in milliseconds.
+    pub fn delta(&self) -> Option<APIint> {
+        Some(self.second? - self.first?)
+    }
+}
+
+/// A breakdown of a QUICK PLAY climb.
+#[derive(Debug, Clone, Serialize)]
+pub struct ZenithRun {
+    /* The ID of the record. */
+    pub id: APIstring,
+    /* Whether the run was played in EXPERT QUICK PLAY. */
+    pub expert: bool,
+    /* The final altitude, in meters. */
+    pub altitude: APIfloat,
+    /* The floor the run ended on. */
+    pub floor: ZenithFloor,
+    /* The duration of the run, in milliseconds. */
+    pub finaltime: APIfloat,
+    /* The floors reached during the run, except the first one. */
+    pub splits: Vec<FloorSplit>,
+    /* The mods enabled for the run. */
+    pub mods: Vec<ZenithMod>,
+    /* Whether the run was a speedrun (all floors reached in time) at the end. */
+    pub speedrun: bool,
+    /* Whether speedrun mode was active at some point during the run. */
+    pub speedrun_seen: bool,
+    /* The amount of revives used. */
+    pub revives: APIint,
+    /* The total amount of revives, including the ones from the partner in duo. */
+    pub revives_total: APIint,
+    /* The peak climb speed rank reached. */
+    pub peak_rank: APIfloat,
+}
+
+struct ZenithRunStats<'a> {
+    altitude: APIfloat,
+    floor: APIint,
+    splits: &'a [APIint],
+    speedrun: bool,
+    speedrun_seen: bool,
+    revives: APIint,
+    revives_total: APIint,
+    peak_rank: APIfloat,
+}
+
+impl ZenithRun {
+    /// Analyses a QUICK PLAY record, returns None if the record does not hold any zenith stats.
+    pub fn from_record(record: &ZenithRecord) -> Option<Self> {
+        let zenith = record.results.stats.zenith.as_ref()?;
+
+        Some(Self::new(
+            record.id.clone(),
+            false,
+            record.results.stats.finaltime,
+            record.extras.zenith.mods.clone(),
+            ZenithRunStats {
+                altitude: zenith.altitude,
+                floor: zenith.floor,
+                splits: &zenith.splits,
+                speedrun: zenith.speedrun,
+                speedrun_seen: zenith.speedrun_seen,
+                revives: zenith.revives,
+                revives_total: zenith.revives_total,
+                peak_rank: zenith.peakrank,
+            },
+        ))
+    }
+
+    /// Analyses an EXPERT QUICK PLAY record, returns None if the record does not hold any zenith stats.
+    pub fn from_ex_record(record: &ZenithExRecord) -> Option<Self> {
+        let zenith = record.results.stats.zenith.as_ref()?;
+
+        Some(Self::new(
+            record.id.clone(),
+            true,
+            record.results.stats.finaltime,
+            record.extras.zenith.mods.clone(),
+            ZenithRunStats {
+                altitude: zenith.altitude,
+                floor: zenith.floor,
+                splits: &zenith.splits,
+                speedrun: zenith.speedrun,
+                speedrun_seen: zenith.speedrun_seen,
+                revives: zenith.revives,
+                revives_total: zenith.revives_total,
+                peak_rank: zenith.peakrank,
+            },
+        ))
+    }
+
+    fn new(id: APIstring, expert: bool, finaltime: APIfloat, mods: Vec<ZenithMod>, stats: ZenithRunStats) -> Self {
+        // splits[i] holds the time at which floor i + 2 was reached, or 0 if it never was.
+        let mut previous = 0;
+        let splits = stats
+            .splits
+            .iter()
+            .zip(FLOORS.iter().skip(1))
+            .take_while(|(time, _)| **time > 0)
+            .map(|(time, floor)| {
+                let split = FloorSplit {
+                    floor: *floor,
+                    reached_at: *time,
+                    time_on_previous_floor: time - previous,
+                };
+                previous = *time;
+                split
+            })
+            .collect();
+
+        Self {
+            id,
+            expert,
+            altitude: stats.altitude,
+            floor: ZenithFloor::from_number(stats.floor).unwrap_or_else(|| ZenithFloor::from_altitude(stats.altitude)),
+            finaltime,
+            splits,
+            mods,
+            speedrun: stats.speedrun,
+            speedrun_seen: stats.speedrun_seen,
+            revives: stats.revives,
+            revives_total: stats.revives_total,
+            peak_rank: stats.peak_rank,
+        }
+    }
+
+    /// The average climb speed over the whole run, in meters per second.
+    pub fn climb_speed(&self) -> Option<APIfloat> {
+        (self.finaltime > 0.0).then(|| self.altitude / (self.finaltime / 1000.0))
+    }
+
+    /// The time spent on every floor, in milliseconds, starting with the first floor.
+    /// The last entry is the time spent on the floor the run ended on.
+    pub fn time_per_floor(&self) -> Vec<(ZenithFloor, APIint)> {
+        let mut times = self
+            .splits
+            .iter()
+            .map(|split| (FLOORS[split.floor.number as usize - 2], split.time_on_previous_floor))
+            .collect::<Vec<_>>();
+
+        let last_floor = self.splits.last().map_or(FLOORS[0], |split| split.floor);
+        let last_split = self.splits.last().map_or(0, |split| split.reached_at);
+        times.push((last_floor, (self.finaltime as APIint - last_split).max(0)));
+
+        times
+    }
+
+    /// Whether any revive was used during the run.
+    pub fn used_revives(&self) -> bool {
+        self.revives > 0
+    }
+
+    /// Whether any reversed mod was enabled.
+    pub fn has_reversed_mods(&self) -> bool {
+        self.mods.iter().any(ZenithMod::is_reversed)
+    }
+
+    /// The time at which a floor was reached, in milliseconds. The first floor is always reached at 0.
+    pub fn reached_at(&self, floor: u8) -> Option<APIint> {
+        if floor == 1 {
+            return Some(0);
+        }
+
+        self.splits.iter().find(|split| split.floor.number == floor).map(|split| split.reached_at)
+    }
+
+    /// Compares two climbs floor by floor.
+    pub fn compare(&self, other: &ZenithRun) -> Vec<FloorComparison> {
+        FLOORS
+            .iter()
+            .skip(1)
+            .map(|floor| FloorComparison {
+                floor: *floor,
+                first: self.reached_at(floor.number),
+                second: other.reached_at(floor.number),
+            })
+            .take_while(|comparison| comparison.first.is_some() || comparison.second.is_some())
+            .collect()
+    }
+}
@@ -0,0 +1,474 @@
+//!
+//! Discord-embed-shaped renderings of users, records and leaderboards.
+//! The structures serialize to the JSON expected by Discord, without depending on any Discord library.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    analysis::{league_match::MatchSummary, solo::{format_time, SoloRun}, zenith::ZenithRun},
+    models::{
+        common::{APIfloat, APIint},
+        users::{
+            summaries::{tetra_league::LeagueSummary, AllSummaries},
+            user_history_leaderboard::LeaderboardUser as HistoricalLeaderboardUser,
+            user_info::UserInfo,
+            user_leaderboard::LeaderboardUser,
+            user_rank::UserRank,
+            user_role::UserRole,
+        },
+    },
+    urls::{UserAssets, Urls},
+};
+
+/* The limits Discord enforces, longer texts are cut and extra fields are dropped. */
+pub const TITLE_LIMIT: usize = 256;
+pub const DESCRIPTION_LIMIT: usize = 4096;
+pub const FIELD_NAME_LIMIT: usize = 256;
+pub const FIELD_VALUE_LIMIT: usize = 1024;
+pub const FIELD_COUNT_LIMIT: usize = 25;
+pub const FOOTER_LIMIT: usize = 2048;
+pub const AUTHOR_LIMIT: usize = 256;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Embed {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /* The link opened when clicking on the title. */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /* The colour of the left border, as a 0xRRGGBB integer. */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<u32>,
+    /* An ISO 8601 timestamp, shown next to the footer. */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<EmbedAuthor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<EmbedImage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub footer: Option<EmbedFooter>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<EmbedField>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbedField {
+    pub name: String,
+    pub value: String,
+    /* Whether the field can be shown next to other inline fields. */
+    #[serde(default)]
+    pub inline: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbedAuthor {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbedImage {
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbedFooter {
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+}
+
+impl Embed {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(truncate(title.into(), TITLE_LIMIT));
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(truncate(description.into(), DESCRIPTION_LIMIT));
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    pub fn color(mut self, color: u32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Uses the colour of a rank, unknown ranks leave the colour unchanged.
+    pub fn rank_color(mut self, rank: &UserRank) -> Self {
+        self.color = rank.color().or(self.color);
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: impl Display) -> Self {
+        self.timestamp = Some(timestamp.to_string());
+        self
+    }
+
+    pub fn author(mut self, name: impl Into<String>, url: Option<String>, icon_url: Option<String>) -> Self {
+        self.author = Some(EmbedAuthor { name: truncate(name.into(), AUTHOR_LIMIT), url, icon_url });
+        self
+    }
+
+    pub fn thumbnail(mut self, url: impl Into<String>) -> Self {
+        self.thumbnail = Some(EmbedImage { url: url.into() });
+        self
+    }
+
+    pub fn footer(mut self, text: impl Into<String>) -> Self {
+        self.footer = Some(EmbedFooter { text: truncate(text.into(), FOOTER_LIMIT), icon_url: None });
+        self
+    }
+
+    /// Adds a field, unless the embed already has the most fields Discord allows.
+    /// Discord rejects empty values, they are replaced by a dash.
+    pub fn field(mut self, name: impl Into<String>, value: impl Into<String>, inline: bool) -> Self {
+        if self.fields.len() < FIELD_COUNT_LIMIT {
+            let value = value.into();
+            self.fields.push(EmbedField {
+                name: truncate(name.into(), FIELD_NAME_LIMIT),
+                value: if value.trim().is_empty() { "—".to_string() } else { truncate(value, FIELD_VALUE_LIMIT) },
+                inline,
+            });
+        }
+        self
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    /// Sets the author to a user, linking to their profile and showing their avatar.
+    fn user(self, user: &impl UserAssets, urls: &Urls) -> Self {
+        self.author(user.username(), Some(urls.user_profile(user)), Some(urls.user_avatar(user)))
+    }
+}
+
+/// Cuts a text to a given amount of characters, ending it with an ellipsis if it was too long.
+fn truncate(text: String, limit: usize) -> String {
+    match text.char_indices().nth(limit) {
+        None => text,
+        Some(_) => {
+            let end = text.char_indices().nth(limit.saturating_sub(1)).map_or(0, |(index, _)| index);
+            format!("{}…", &text[..end])
+        }
+    }
+}
+
+/// Joins lines until they would not fit in the limit, also returning how many lines were kept.
+fn join_lines(lines: impl IntoIterator<Item = String>, limit: usize) -> (String, usize) {
+    let mut joined = String::new();
+    let mut count = 0;
+    for line in lines {
+        if joined.chars().count() + line.chars().count() + 1 > limit {
+            break;
+        }
+        if !joined.is_empty() {
+            joined.push('\n');
+        }
+        joined.push_str(&line);
+        count += 1;
+    }
+    (joined, count)
+}
+
+fn role_label(role: &UserRole) -> String {
+    match role {
+        UserRole::Anon => "ANON".to_string(),
+        UserRole::User => "USER".to_string(),
+        UserRole::Bot => "BOT".to_string(),
+        UserRole::Mod => "MOD".to_string(),
+        UserRole::Admin => "ADMIN".to_string(),
+        UserRole::Banned => "BANNED".to_string(),
+        UserRole::HalfMod => "HALF-MOD".to_string(),
+        UserRole::SysOp => "SYSOP".to_string(),
+        UserRole::Hidden => "HIDDEN".to_string(),
+        UserRole::Unknown(role) => role.to_uppercase(),
+    }
+}
+
+/// The API uses -1 for missing stats.
+fn stat(value: Option<APIfloat>) -> Option<APIfloat> {
+    value.filter(|value| *value >= 0.0)
+}
+
+/// The flag emoji of an ISO 3166-1 country code, empty for codes which are not two letters.
+fn flag(country: Option<&str>) -> String {
+    match country {
+        Some(code) if code.len() == 2 && code.chars().all(|c| c.is_ascii_alphabetic()) => code
+            .to_ascii_uppercase()
+            .chars()
+            .filter_map(|c| char::from_u32(0x1F1E6 + (c as u32 - 'A' as u32)))
+            .chain([' '])
+            .collect(),
+        _ => String::new(),
+    }
+}
+
+/// Formats an integer with thousands separators (e.g. "1,234,567").
+fn thousands(value: APIint) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let mut formatted = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            formatted.push(',');
+        }
+        formatted.push(digit);
+    }
+    if value < 0 {
+        formatted.insert(0, '-');
+    }
+    formatted
+}
+
+fn games(played: APIint, won: APIint) -> String {
+    match played {
+        0 => "0 played".to_string(),
+        _ => format!("{won} / {played} won ({:.1}%)", won as APIfloat / played as APIfloat * 100.0),
+    }
+}
+
+/// A user's profile: country, role, XP, games, play time, achievement rating and badges.
+pub fn user_embed(info: &UserInfo, urls: &Urls) -> Embed {
+    let mut embed = Embed::new()
+        .title(info.username.clone())
+        .url(urls.user_profile(info))
+        .thumbnail(urls.user_avatar(info));
+
+    if let Some(ts) = &info.ts {
+        embed = embed.footer("Joined").timestamp(ts);
+    }
+    if let Some(bio) = info.bio.as_deref().filter(|bio| !bio.trim().is_empty()) {
+        embed = embed.description(bio);
+    }
+    if let Some(country) = &info.country {
+        embed = embed.field("Country", format!("{}{country}", flag(Some(country))), true);
+    }
+    if info.role != UserRole::User {
+        embed = embed.field("Role", role_label(&info.role), true);
+    }
+
+    embed = embed.field("XP", thousands(info.xp as APIint), true);
+    if info.gamesplayed >= 0 {
+        embed = embed.field("Games", games(info.gamesplayed, info.gameswon.max(0)), true);
+    }
+    if info.gametime >= 0.0 {
+        let minutes = (info.gametime / 60.0) as APIint;
+        embed = embed.field("Play time", format!("{}h {:02}m", thousands(minutes / 60), minutes % 60), true);
+    }
+    embed = embed.field("Achievement rating", thousands(info.ar), true);
+    if !info.badges.is_empty() {
+        embed = embed.field("Badges", info.badges.len().to_string(), true);
+    }
+
+    embed
+}
+
+/// A user's TETRA LEAGUE standing, coloured like their rank.
+pub fn league_embed(user: &impl UserAssets, league: &LeagueSummary, urls: &Urls) -> Embed {
+    let mut embed = Embed::new().user(user, urls).title("TETRA LEAGUE").url(urls.user_profile(user));
+
+    let rank = league.rank.as_ref().filter(|rank| **rank != UserRank::Z);
+    match (rank, stat(league.tr)) {
+        (Some(rank), Some(tr)) => {
+            embed = embed.rank_color(rank).thumbnail(urls.rank_icon(rank));
+
+            let best = league.bestrank.as_ref().filter(|best| *best != rank && **best != UserRank::Z);
+            embed = embed.field("Rank", best.map_or(rank.to_string(), |best| format!("{rank} (best {best})")), true);
+            embed = embed.field("TR", format!("{tr:.2}"), true);
+            if let Some(gxe) = stat(league.gxe) {
+                embed = embed.field("GLIXARE", format!("{gxe:.2}%"), true);
+            }
+            if let (Some(glicko), Some(rd)) = (stat(league.glicko), stat(league.rd)) {
+                embed = embed.field("Glicko", format!("{glicko:.0} ± {rd:.0}"), true);
+            }
+
+            let standing = league.standing.filter(|standing| *standing > 0);
+            let standing_local = league.standing_local.filter(|standing| *standing > 0);
+            match (standing, standing_local) {
+                (Some(standing), Some(local)) => embed = embed.field("Standing", format!("#{} (#{} local)", thousands(standing), thousands(local)), true),
+                (Some(standing), None) => embed = embed.field("Standing", format!("#{}", thousands(standing)), true),
+                _ => {}
+            }
+        }
+        _ => embed = embed.thumbnail(urls.user_avatar(user)).description("Unranked"),
+    }
+
+    if let Some(played) = league.gamesplayed.filter(|played| *played >= 0) {
+        embed = embed.field("Games", games(played, league.gameswon.unwrap_or(0).max(0)), true);
+    }
+    for (name, value) in [("APM", league.apm), ("PPS", league.pps), ("VS", league.vs)] {
+        if let Some(value) = stat(value) {
+            embed = embed.field(name, format!("{value:.2}"), true);
+        }
+    }
+    if league.decaying == Some(true) {
+        embed = embed.footer("Rating deviation is rising");
+    }
+
+    embed
+}
+
+/// A user's best run in every mode, coloured like their TETRA LEAGUE rank.
+pub fn summaries_embed(user: &impl UserAssets, summaries: &AllSummaries, urls: &Urls) -> Embed {
+    let mut embed = Embed::new().user(user, urls).title("Records").url(urls.user_profile(user)).thumbnail(urls.user_avatar(user));
+    if let Some(rank) = &summaries.league.rank {
+        embed = embed.rank_color(rank);
+    }
+
+    let placement = |rank: APIint| if rank > 0 { format!(" (#{})", thousands(rank)) } else { String::new() };
+    let sprint = summaries.sprint.record.as_ref().map(SoloRun::from_sprint_record);
+    let blitz = summaries.blitz.record.as_ref().map(SoloRun::from_blitz_record);
+    let zenith = summaries.zenith.best.record.as_ref().and_then(ZenithRun::from_record);
+    let zenithex = summaries.zenithex.best.record.as_ref().and_then(ZenithRun::from_ex_record);
+
+    embed = embed
+        .field("40 LINES", sprint.map_or("—".to_string(), |run| format!("{}{}", run.formatted_time(), placement(summaries.sprint.rank))), true)
+        .field("BLITZ", blitz.map_or("—".to_string(), |run| format!("{}{}", thousands(run.score), placement(summaries.blitz.rank))), true)
+        .field("QUICK PLAY", zenith.map_or("—".to_string(), |run| format!("{:.1} m{}", run.altitude, placement(summaries.zenith.best.rank))), true)
+        .field("EXPERT QUICK PLAY", zenithex.map_or("—".to_string(), |run| format!("{:.1} m{}", run.altitude, placement(summaries.zenithex.best.rank))), true);
+
+    let league = &summaries.league;
+    let league_value = match (league.rank.as_ref().filter(|rank| **rank != UserRank::Z), stat(league.tr)) {
+        (Some(rank), Some(tr)) => format!("{rank} · {tr:.2} TR"),
+        _ => "Unranked".to_string(),
+    };
+
+    embed
+        .field("TETRA LEAGUE", league_value, true)
+        .field("ZEN", format!("Level {}", thousands(summaries.zen.level)), true)
+}
+
+/// The recap of a TETRA LEAGUE match, coloured like the winner's rank.
+pub fn match_embed(summary: &MatchSummary, urls: &Urls) -> Embed {
+    let names = summary.players.iter().map(|player| player.username.as_str()).collect::<Vec<_>>();
+    let winner = summary.winner();
+
+    let mut embed = Embed::new()
+        .title(names.join(" vs "))
+        .url(urls.replay(&summary.replayid))
+        .description(match winner {
+            Some(winner) => format!("**{}** · {} wins", summary.score_line(), winner.username),
+            None => format!("**{}**", summary.score_line()),
+        })
+        .timestamp(&summary.ts)
+        .footer("TETRA LEAGUE");
+
+    if let Some(rating) = winner.and_then(|winner| winner.after.as_ref().or(winner.before.as_ref())) {
+        embed = embed.rank_color(&rating.rank);
+    }
+
+    for player in &summary.players {
+        let mut lines = vec![];
+        for (name, value) in [("APM", player.apm), ("PPS", player.pps), ("VS", player.vs)] {
+            if let Some(value) = value {
+                lines.push(format!("{name} {value:.2}"));
+            }
+        }
+        if let (Some(before), Some(after), Some(delta)) = (&player.before, &player.after, player.tr_delta()) {
+            lines.push(format!("TR {:.2} → {:.2} ({delta:+.2})", before.tr, after.tr));
+            if player.rank_changed() {
+                lines.push(format!("Rank {} → {}", before.rank, after.rank));
+            }
+        }
+
+        let name = match winner {
+            Some(winner) if winner.id == player.id => format!("{} (winner)", player.username),
+            _ => player.username.clone(),
+        };
+        embed = embed.field(name, lines.join("\n"), true);
+    }
+
+    let rounds = summary.rounds.iter().map(|round| {
+        let winner = round.winner.as_deref().and_then(|id| summary.player(id)).map_or("—", |player| player.username.as_str());
+        format!("{}. {winner} ({})", round.index + 1, format_time(round.duration as APIfloat))
+    });
+    if !summary.rounds.is_empty() {
+        embed = embed.field("Rounds", join_lines(rounds, FIELD_VALUE_LIMIT).0, false);
+    }
+
+    embed
+}
+
+/// A QUICK PLAY or EXPERT QUICK PLAY run.
+pub fn zenith_embed(user: &impl UserAssets, run: &ZenithRun, urls: &Urls) -> Embed {
+    let mut embed = Embed::new()
+        .user(user, urls)
+        .title(if run.expert { "EXPERT QUICK PLAY" } else { "QUICK PLAY" })
+        .url(urls.record(&run.id))
+        .thumbnail(urls.user_avatar(user))
+        .description(format!("**{:.1} m** · Floor {} — {}", run.altitude, run.floor.number, run.floor.name))
+        .field("Time", format_time(run.finaltime), true);
+
+    if let Some(speed) = run.climb_speed() {
+        embed = embed.field("Climb speed", format!("{speed:.2} m/s"), true);
+    }
+    embed = embed.field("Peak rank", format!("{:.2}", run.peak_rank), true);
+    if run.revives > 0 {
+        embed = embed.field("Revives", run.revives.to_string(), true);
+    }
+    if run.speedrun {
+        embed = embed.field("Speedrun", "Yes", true);
+    }
+    if !run.mods.is_empty() {
+        embed = embed.field("Mods", run.mods.iter().map(|mod_| mod_.card_name()).collect::<Vec<_>>().join(", "), false);
+    }
+
+    embed
+}
+
+fn leaderboard_page(title: impl Into<String>, lines: Vec<String>, first: usize, color: Option<&UserRank>) -> Embed {
+    let (description, count) = join_lines(lines, DESCRIPTION_LIMIT);
+    let mut embed = Embed::new().title(title).description(description);
+    if let Some(rank) = color {
+        embed = embed.rank_color(rank);
+    }
+    if count > 0 {
+        embed = embed.footer(format!("#{} – #{}", thousands(first as APIint), thousands((first + count - 1) as APIint)));
+    }
+    embed
+}
+
+/// A page of the TETRA LEAGUE leaderboard, `first_position` being the position of the first entry.
+/// Entries which do not fit in the description are left out.
+pub fn leaderboard_embed(title: impl Into<String>, entries: &[LeaderboardUser], first_position: usize) -> Embed {
+    let lines = entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let rank = entry.league.rank.as_ref().map_or("?".to_string(), |rank| rank.to_string());
+            format!("`#{}` {}**{}** · {rank} · {:.2} TR", first_position + index, flag(entry.country.as_deref()), entry.username, entry.league.tr)
+        })
+        .collect();
+
+    let color = entries.first().and_then(|entry| entry.league.rank.as_ref());
+    leaderboard_page(title, lines, first_position, color)
+}
+
+/// A page of a past season's leaderboard, using the placements of the entries.
+pub fn historical_leaderboard_embed(title: impl Into<String>, entries: &[HistoricalLeaderboardUser]) -> Embed {
+    let lines = entries
+        .iter()
+        .map(|entry| format!("`#{}` {}**{}** · {} · {:.2} TR", entry.placement, flag(entry.country.as_deref()), entry.username, entry.rank, entry.tr))
+        .collect();
+
+    let first = entries.first().map_or(1, |entry| entry.placement.max(1) as usize);
+    leaderboard_page(title, lines, first, entries.first().map(|entry| &entry.rank))
+}
@@ -0,0 +1,417 @@
+//!
+//! Flat exports of leaderboards and records, with one column per stat.
+//! Every type has a fixed list of columns, so files exported at different times can be loaded together.
+
+use std::io::Write;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use self::ColumnKind::{Bool, Float, Int, Text};
+
+use crate::models::{
+    common::{APIfloat, APIint},
+    users::{
+        user_history_leaderboard::LeaderboardUser as HistoricalLeaderboardUser,
+        user_leaderboard::LeaderboardUser,
+        user_records::{BlitzRecord, LeagueRecord, SprintRecord, ZenithExRecord, ZenithRecord},
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnKind {
+    Int,
+    Float,
+    Bool,
+    /* Lists are joined with commas. */
+    Text,
+}
+
+/// A column of an export, read from the serialized rows with a JSON pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Column {
+    pub name: &'static str,
+    pub pointer: &'static str,
+    pub kind: ColumnKind,
+}
+
+impl Column {
+    pub const fn new(name: &'static str, pointer: &'static str, kind: ColumnKind) -> Self {
+        Self { name, pointer, kind }
+    }
+}
+
+/// A single value of an export, Null when the row does not have it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    Null,
+    Int(APIint),
+    Float(APIfloat),
+    Bool(bool),
+    Text(String),
+}
+
+impl Cell {
+    fn read(row: &Value, column: &Column) -> Cell {
+        let value = match row.pointer(column.pointer) {
+            None | Some(Value::Null) => return Cell::Null,
+            Some(value) => value,
+        };
+
+        let cell = match column.kind {
+            ColumnKind::Int => value.as_i64().or_else(|| value.as_f64().map(|value| value.round() as APIint)).map(Cell::Int),
+            ColumnKind::Float => value.as_f64().map(Cell::Float),
+            ColumnKind::Bool => value.as_bool().map(Cell::Bool),
+            ColumnKind::Text => Some(Cell::Text(text(value))),
+        };
+        cell.unwrap_or(Cell::Null)
+    }
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        Value::Array(values) => values.iter().map(text).collect::<Vec<_>>().join(","),
+        value => value.to_string(),
+    }
+}
+
+/// A model which can be exported, one or more rows per item.
+pub trait Exportable: Serialize {
+    const COLUMNS: &'static [Column];
+
+    /// The rows the columns are read from, the serialized item by default.
+    fn rows(&self) -> Vec<Value> {
+        vec![serde_json::to_value(self).unwrap_or_default()]
+    }
+}
+
+/// The cells of every row, in the order of [`Exportable::COLUMNS`].
+pub fn cells<T: Exportable>(items: &[T]) -> Vec<Vec<Cell>> {
+    items
+        .iter()
+        .flat_map(Exportable::rows)
+        .map(|row| T::COLUMNS.iter().map(|column| Cell::read(&row, column)).collect())
+        .collect()
+}
+
+/// Writes a CSV file with a header, missing values are left empty.
+pub fn write_csv<T: Exportable>(mut writer: impl Write, items: &[T]) -> std::io::Result<()> {
+    let header = T::COLUMNS.iter().map(|column| csv_escape(column.name)).collect::<Vec<_>>();
+    writeln!(writer, "{}", header.join(","))?;
+
+    for row in cells(items) {
+        let values = row
+            .into_iter()
+            .map(|cell| match cell {
+                Cell::Null => String::new(),
+                Cell::Int(value) => value.to_string(),
+                Cell::Float(value) => value.to_string(),
+                Cell::Bool(value) => value.to_string(),
+                Cell::Text(value) => csv_escape(&value),
+            })
+            .collect::<Vec<_>>();
+        writeln!(writer, "{}", values.join(","))?;
+    }
+
+    writer.flush()
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Writes one JSON object per line, with every column in order, missing values being null.
+pub fn write_ndjson<T: Exportable>(mut writer: impl Write, items: &[T]) -> std::io::Result<()> {
+    for row in cells(items) {
+        let fields = T::COLUMNS
+            .iter()
+            .zip(row)
+            .map(|(column, cell)| {
+                let value = match cell {
+                    Cell::Null => Value::Null,
+                    Cell::Int(value) => json!(value),
+                    Cell::Float(value) => json!(value),
+                    Cell::Bool(value) => json!(value),
+                    Cell::Text(value) => json!(value),
+                };
+                format!("{}:{value}", Value::from(column.name))
+            })
+            .collect::<Vec<_>>();
+        writeln!(writer, "{{{}}}", fields.join(","))?;
+    }
+
+    writer.flush()
+}
+
+/// Writes a Parquet file with a single row group, every column being optional.
+#[cfg(feature = "parquet_export")]
+pub fn write_parquet<T: Exportable>(writer: impl Write + Send, items: &[T]) -> std::io::Result<()> {
+    parquet_writer::write(writer, T::COLUMNS, cells(items)).map_err(std::io::Error::other)
+}
+
+#[cfg(feature = "parquet_export")]
+mod parquet_writer {
+    use std::io::Write;
+    use std::sync::Arc;
+
+    use parquet::{
+        basic::{LogicalType, Repetition, Type as PhysicalType},
+        data_type::{BoolType, ByteArray, ByteArrayType, DoubleType, Int64Type},
+        errors::Result,
+        file::{properties::WriterProperties, writer::SerializedFileWriter},
+        schema::types::Type,
+    };
+
+    use super::{Cell, Column, ColumnKind};
+
+    pub(super) fn write(writer: impl Write + Send, columns: &[Column], rows: Vec<Vec<Cell>>) -> Result<()> {
+        let fields = columns
+            .iter()
+            .map(|column| {
+                let (physical, logical) = match column.kind {
+                    ColumnKind::Int => (PhysicalType::INT64, None),
+                    ColumnKind::Float => (PhysicalType::DOUBLE, None),
+                    ColumnKind::Bool => (PhysicalType::BOOLEAN, None),
+                    ColumnKind::Text => (PhysicalType::BYTE_ARRAY, Some(LogicalType::String)),
+                };
+                Type::primitive_type_builder(column.name, physical)
+                    .with_repetition(Repetition::OPTIONAL)
+                    .with_logical_type(logical)
+                    .build()
+                    .map(Arc::new)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let schema = Arc::new(Type::group_type_builder("schema").with_fields(fields).build()?);
+
+        let mut writer = SerializedFileWriter::new(writer, schema, Arc::new(WriterProperties::builder().build()))?;
+        let mut row_group = writer.next_row_group()?;
+
+        for (index, column) in columns.iter().enumerate() {
+            let Some(mut column_writer) = row_group.next_column()? else { break };
+
+            // Null cells only get a definition level of 0, without any value.
+            let cells = rows.iter().map(|row| &row[index]);
+            let levels = cells.clone().map(|cell| i16::from(*cell != Cell::Null)).collect::<Vec<_>>();
+
+            match column.kind {
+                ColumnKind::Int => {
+                    let values = cells.filter_map(|cell| if let Cell::Int(value) = cell { Some(*value) } else { None }).collect::<Vec<_>>();
+                    column_writer.typed::<Int64Type>().write_batch(&values, Some(&levels), None)?;
+                }
+                ColumnKind::Float => {
+                    let values = cells.filter_map(|cell| if let Cell::Float(value) = cell { Some(*value) } else { None }).collect::<Vec<_>>();
+                    column_writer.typed::<DoubleType>().write_batch(&values, Some(&levels), None)?;
+                }
+                ColumnKind::Bool => {
+                    let values = cells.filter_map(|cell| if let Cell::Bool(value) = cell { Some(*value) } else { None }).collect::<Vec<_>>();
+                    column_writer.typed::<BoolType>().write_batch(&values, Some(&levels), None)?;
+                }
+                ColumnKind::Text => {
+                    let values = cells
+                        .filter_map(|cell| if let Cell::Text(value) = cell { Some(ByteArray::from(value.as_str())) } else { None })
+                        .collect::<Vec<_>>();
+                    column_writer.typed::<ByteArrayType>().write_batch(&values, Some(&levels), None)?;
+                }
+            }
+            column_writer.close()?;
+        }
+
+        row_group.close()?;
+        writer.close()?;
+        Ok(())
+    }
+}
+
+impl Exportable for LeaderboardUser {
+    const COLUMNS: &'static [Column] = &[
+        Column::new("id", "/_id", Text),
+        Column::new("username", "/username", Text),
+        Column::new("role", "/role", Text),
+        Column::new("ts", "/ts", Text),
+        Column::new("country", "/country", Text),
+        Column::new("supporter", "/supporter", Bool),
+        Column::new("xp", "/xp", Float),
+        Column::new("gamesplayed", "/gamesplayed", Int),
+        Column::new("gameswon", "/gameswon", Int),
+        Column::new("gametime", "/gametime", Float),
+        Column::new("friend_count", "/friend_count", Int),
+        Column::new("ar", "/ar", Int),
+        Column::new("league_gamesplayed", "/league/gamesplayed", Int),
+        Column::new("league_gameswon", "/league/gameswon", Int),
+        Column::new("league_tr", "/league/tr", Float),
+        Column::new("league_gxe", "/league/gxe", Float),
+        Column::new("league_rank", "/league/rank", Text),
+        Column::new("league_bestrank", "/league/bestrank", Text),
+        Column::new("league_glicko", "/league/glicko", Float),
+        Column::new("league_rd", "/league/rd", Float),
+        Column::new("league_apm", "/league/apm", Float),
+        Column::new("league_pps", "/league/pps", Float),
+        Column::new("league_vs", "/league/vs", Float),
+        Column::new("league_decaying", "/league/decaying", Bool),
+    ];
+}
+
+impl Exportable for HistoricalLeaderboardUser {
+    const COLUMNS: &'static [Column] = &[
+        Column::new("id", "/_id", Text),
+        Column::new("season", "/season", Text),
+        Column::new("username", "/username", Text),
+        Column::new("country", "/country", Text),
+        Column::new("placement", "/placement", Int),
+        Column::new("ranked", "/ranked", Bool),
+        Column::new("gamesplayed", "/gamesplayed", Int),
+        Column::new("gameswon", "/gameswon", Int),
+        Column::new("tr", "/tr", Float),
+        Column::new("gxe", "/gxe", Float),
+        Column::new("rank", "/rank", Text),
+        Column::new("bestrank", "/bestrank", Text),
+        Column::new("glicko", "/glicko", Float),
+        Column::new("rd", "/rd", Float),
+        Column::new("apm", "/apm", Float),
+        Column::new("pps", "/pps", Float),
+        Column::new("vs", "/vs", Float),
+    ];
+}
+
+/// The columns shared by 40 LINES, BLITZ, QUICK PLAY and EXPERT QUICK PLAY records, which all serialize the same way.
+/// Columns a mode does not have, like the zenith stats of a 40 LINES record, are null.
+pub const SOLO_RECORD_COLUMNS: &[Column] = &[
+    Column::new("id", "/_id", Text),
+    Column::new("replayid", "/replayid", Text),
+    Column::new("gamemode", "/gamemode", Text),
+    Column::new("ts", "/ts", Text),
+    Column::new("pb", "/pb", Bool),
+    Column::new("oncepb", "/oncepb", Bool),
+    Column::new("disputed", "/disputed", Bool),
+    Column::new("user_id", "/user/id", Text),
+    Column::new("username", "/user/username", Text),
+    Column::new("country", "/user/country", Text),
+    Column::new("apm", "/results/aggregatestats/apm", Float),
+    Column::new("pps", "/results/aggregatestats/pps", Float),
+    Column::new("vsscore", "/results/aggregatestats/vsscore", Float),
+    Column::new("gameoverreason", "/results/gameoverreason", Text),
+    Column::new("finaltime", "/results/stats/finaltime", Float),
+    Column::new("score", "/results/stats/score", Int),
+    Column::new("lines", "/results/stats/lines", Int),
+    Column::new("level", "/results/stats/level", Int),
+    Column::new("inputs", "/results/stats/inputs", Int),
+    Column::new("holds", "/results/stats/holds", Int),
+    Column::new("piecesplaced", "/results/stats/piecesplaced", Int),
+    Column::new("topcombo", "/results/stats/topcombo", Int),
+    Column::new("topbtb", "/results/stats/topbtb", Int),
+    Column::new("tspins", "/results/stats/tspins", Int),
+    Column::new("kills", "/results/stats/kills", Int),
+    Column::new("clears_singles", "/results/stats/clears/singles", Int),
+    Column::new("clears_doubles", "/results/stats/clears/doubles", Int),
+    Column::new("clears_triples", "/results/stats/clears/triples", Int),
+    Column::new("clears_quads", "/results/stats/clears/quads", Int),
+    Column::new("clears_pentas", "/results/stats/clears/pentas", Int),
+    Column::new("clears_realtspins", "/results/stats/clears/realtspins", Int),
+    Column::new("clears_minitspins", "/results/stats/clears/minitspins", Int),
+    Column::new("clears_minitspinsingles", "/results/stats/clears/minitspinsingles", Int),
+    Column::new("clears_tspinsingles", "/results/stats/clears/tspinsingles", Int),
+    Column::new("clears_minitspindoubles", "/results/stats/clears/minitspindoubles", Int),
+    Column::new("clears_tspindoubles", "/results/stats/clears/tspindoubles", Int),
+    Column::new("clears_minitspintriples", "/results/stats/clears/minitspintriples", Int),
+    Column::new("clears_tspintriples", "/results/stats/clears/tspintriples", Int),
+    Column::new("clears_minitspinquads", "/results/stats/clears/minitspinquads", Int),
+    Column::new("clears_tspinquads", "/results/stats/clears/tspinquads", Int),
+    Column::new("clears_tspinpentas", "/results/stats/clears/tspinpentas", Int),
+    Column::new("clears_allclear", "/results/stats/clears/allclear", Int),
+    Column::new("garbage_sent", "/results/stats/garbage/sent", Int),
+    Column::new("garbage_sent_nomult", "/results/stats/garbage/sent_nomult", Int),
+    Column::new("garbage_maxspike", "/results/stats/garbage/maxspike", Int),
+    Column::new("garbage_maxspike_nomult", "/results/stats/garbage/maxspike_nomult", Int),
+    Column::new("garbage_received", "/results/stats/garbage/received", Int),
+    Column::new("garbage_attack", "/results/stats/garbage/attack", Int),
+    Column::new("garbage_cleared", "/results/stats/garbage/cleared", Int),
+    Column::new("finesse_combo", "/results/stats/finesse/combo", Int),
+    Column::new("finesse_faults", "/results/stats/finesse/faults", Int),
+    Column::new("finesse_perfectpieces", "/results/stats/finesse/perfectpieces", Int),
+    Column::new("zenith_altitude", "/results/stats/zenith/altitude", Float),
+    Column::new("zenith_rank", "/results/stats/zenith/rank", Float),
+    Column::new("zenith_peakrank", "/results/stats/zenith/peakrank", Float),
+    Column::new("zenith_avgrankpts", "/results/stats/zenith/avgrankpts", Float),
+    Column::new("zenith_floor", "/results/stats/zenith/floor", Int),
+    Column::new("zenith_targetingfactor", "/results/stats/zenith/targetingfactor", Float),
+    Column::new("zenith_targetinggrace", "/results/stats/zenith/targetinggrace", Float),
+    Column::new("zenith_totalbonus", "/results/stats/zenith/totalbonus", Float),
+    Column::new("zenith_revives", "/results/stats/zenith/revives", Int),
+    Column::new("zenith_revives_total", "/results/stats/zenith/revivesTotal", Int),
+    Column::new("zenith_speedrun", "/results/stats/zenith/speedrun", Bool),
+    Column::new("zenith_speedrun_seen", "/results/stats/zenith/speedrun_seen", Bool),
+    Column::new("zenith_mods", "/extras/zenith/mods", Text),
+];
+
+impl Exportable for SprintRecord {
+    const COLUMNS: &'static [Column] = SOLO_RECORD_COLUMNS;
+}
+
+impl Exportable for BlitzRecord {
+    const COLUMNS: &'static [Column] = SOLO_RECORD_COLUMNS;
+}
+
+impl Exportable for ZenithRecord {
+    const COLUMNS: &'static [Column] = SOLO_RECORD_COLUMNS;
+}
+
+impl Exportable for ZenithExRecord {
+    const COLUMNS: &'static [Column] = SOLO_RECORD_COLUMNS;
+}
+
+/// One row per player of the match, with their rating before and after it.
+impl Exportable for LeagueRecord {
+    const COLUMNS: &'static [Column] = &[
+        Column::new("id", "/id", Text),
+        Column::new("replayid", "/replayid", Text),
+        Column::new("ts", "/ts", Text),
+        Column::new("user_id", "/user_id", Text),
+        /* The result from the point of view of the record's owner. */
+        Column::new("result", "/result", Text),
+        Column::new("player_id", "/player/id", Text),
+        Column::new("player_username", "/player/username", Text),
+        Column::new("player_active", "/player/active", Bool),
+        Column::new("wins", "/player/wins", Int),
+        Column::new("apm", "/player/stats/apm", Float),
+        Column::new("pps", "/player/stats/pps", Float),
+        Column::new("vsscore", "/player/stats/vsscore", Float),
+        Column::new("garbage_sent", "/player/stats/garbagesent", Float),
+        Column::new("garbage_received", "/player/stats/garbagereceived", Float),
+        Column::new("kills", "/player/stats/kills", Float),
+        Column::new("tr_before", "/before/tr", Float),
+        Column::new("glicko_before", "/before/glicko", Float),
+        Column::new("rd_before", "/before/rd", Float),
+        Column::new("rank_before", "/before/rank", Text),
+        Column::new("tr_after", "/after/tr", Float),
+        Column::new("glicko_after", "/after/glicko", Float),
+        Column::new("rd_after", "/after/rd", Float),
+        Column::new("rank_after", "/after/rank", Text),
+    ];
+
+    fn rows(&self) -> Vec<Value> {
+        self.results
+            .leaderboard
+            .iter()
+            .map(|player| {
+                let ratings = self.extras.league.get(&player.id);
+                let rating = |index: usize| ratings.and_then(|ratings| ratings.get(index)).map_or(Value::Null, |rating| json!(rating));
+
+                json!({
+                    "id": self.id,
+                    "replayid": self.replayid,
+                    "ts": self.ts,
+                    "user_id": self.user.as_ref().map(|user| &user.id),
+                    "result": self.extras.result,
+                    "player": player,
+                    "before": rating(0),
+                    "after": rating(1),
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+/// How far a batch of requests got, reported every time a user is done.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BatchProgress {
+    pub done: usize,
+    pub total: usize,
+    /* The users which were served from the cache. */
+    pub cached: usize,
+    /* The users whose request failed or got an unsuccessful packet. */
+    pub failed: usize,
+    pub elapsed: Duration,
+    /* The estimated time left, None until the first request to the API is done. */
+    pub eta: Option<Duration>,
+}
+
+impl BatchProgress {
+    /// The share (0~1) of users done.
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            return 1.0;
+        }
+
+        self.done as f64 / self.total as f64
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.done >= self.total
+    }
+}
+
+pub(crate) struct ProgressTracker {
+    start: Instant,
+    fetch_start: Option<Instant>,
+    total: usize,
+    cached: usize,
+    fetched: usize,
+    failed: usize,
+}
+
+impl ProgressTracker {
+    pub(crate) fn new(total: usize) -> Self {
+        Self {
+            start: Instant::now(),
+            fetch_start: None,
+            total,
+            cached: 0,
+            fetched: 0,
+            failed: 0,
+        }
+    }
+
+    pub(crate) fn cached(&mut self, success: bool) -> BatchProgress {
+        self.cached += 1;
+        self.failed += usize::from(!success);
+        self.report()
+    }
+
+    /// Called once the requests which weren't cached start, so the time spent reading the cache isn't part of the ETA.
+    pub(crate) fn start_fetching(&mut self) {
+        self.fetch_start = Some(Instant::now());
+    }
+
+    pub(crate) fn fetched(&mut self, success: bool) -> BatchProgress {
+        self.fetched += 1;
+        self.failed += usize::from(!success);
+        self.report()
+    }
+
+    fn report(&self) -> BatchProgress {
+        let done = self.cached + self.fetched;
+        let eta = match (self.fetch_start, self.fetched) {
+            _ if done >= self.total => Some(Duration::ZERO),
+            (Some(fetch_start), fetched) if fetched > 0 => Some(fetch_start.elapsed() / fetched as u32 * (self.total - done) as u32),
+            _ => None,
+        };
+
+        BatchProgress {
+            done,
+            total: self.total,
+            cached: self.cached,
+            failed: self.failed,
+            elapsed: self.start.elapsed(),
+            eta,
+        }
+    }
+}
+
+/// Removes the duplicated user references, keeping the order they first appear in.
+/// Usernames are case insensitive, so references are lowercased.
+pub fn dedupe_users<'a>(users: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut seen = HashSet::new();
+
+    users
+        .into_iter()
+        .map(|user| user.trim().to_lowercase())
+        .filter(|user| !user.is_empty() && seen.insert(user.clone()))
+        .collect()
+}
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// An amount of requests allowed per window of time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Budget {
+    pub requests: u32,
+    pub window: Duration,
+}
+
+impl Budget {
+    pub fn new(requests: u32, window: Duration) -> Self {
+        Self { requests, window }
+    }
+
+    pub fn per_minute(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(60))
+    }
+}
+
+/// What happens to the requests of a tenant which used up its budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OnExhausted {
+    /// Requests which aren't cached fail with [`Error::BudgetExhausted`](super::error::Error::BudgetExhausted).
+    #[default]
+    FailFast,
+    /// Requests which aren't cached get an unsuccessful packet, like the ones the API sends when a request fails.
+    CacheOnly,
+}
+
+/// How much of its budget a tenant has left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BudgetStatus {
+    pub limit: u32,
+    pub remaining: u32,
+    /* The time left before the budget is refilled. */
+    pub resets_in: Duration,
+}
+
+impl BudgetStatus {
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+impl Display for BudgetStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{} requests left, resets in {:.1}s", self.remaining, self.limit, self.resets_in.as_secs_f64())
+    }
+}
+
+struct Usage {
+    since: Instant,
+    used: u32,
+}
+
+/// Request budgets for the tenants sharing a client, like the Discord guilds a bot is in.
+///
+/// Each tenant gets its own amount of requests per window, counted from its first request.
+/// Only requests sent to the API are counted, requests answered from the cache are free.
+pub struct TenantBudgets {
+    default: Budget,
+    budgets: HashMap<String, Budget>,
+    on_exhausted: OnExhausted,
+    usage: Mutex<HashMap<String, Usage>>,
+}
+
+impl TenantBudgets {
+    /// Gives every tenant the same budget.
+    pub fn new(default: Budget) -> Self {
+        Self {
+            default,
+            budgets: HashMap::new(),
+            on_exhausted: OnExhausted::default(),
+            usage: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Gives a tenant a budget different from the default one.
+    pub fn with_budget(mut self, tenant: impl Into<String>, budget: Budget) -> Self {
+        self.budgets.insert(tenant.into(), budget);
+        self
+    }
+
+    pub fn when_exhausted(mut self, on_exhausted: OnExhausted) -> Self {
+        self.on_exhausted = on_exhausted;
+        self
+    }
+
+    pub fn on_exhausted(&self) -> OnExhausted {
+        self.on_exhausted
+    }
+
+    pub fn budget(&self, tenant: &str) -> Budget {
+        self.budgets.get(tenant).copied().unwrap_or(self.default)
+    }
+
+    /// The budget a tenant has left, without using any of it.
+    pub fn remaining(&self, tenant: &str) -> BudgetStatus {
+        let budget = self.budget(tenant);
+        let usage = self.lock();
+
+        Self::status(budget, usage.get(tenant), Instant::now())
+    }
+
+    /// Uses one request of a tenant's budget.
+    /// Returns what is left afterwards, or the exhausted budget if there was nothing left to use.
+    pub fn try_spend(&self, tenant: &str) -> Result<BudgetStatus, BudgetStatus> {
+        let budget = self.budget(tenant);
+        let now = Instant::now();
+        let mut usage = self.lock();
+
+        if !usage.contains_key(tenant) {
+            // Forgets the tenants whose window is over, they would start a new one anyway.
+            usage.retain(|tenant, usage| now.duration_since(usage.since) < self.budget(tenant).window);
+        }
+
+        let entry = usage.entry(tenant.to_string()).or_insert(Usage { since: now, used: 0 });
+        if now.duration_since(entry.since) >= budget.window {
+            *entry = Usage { since: now, used: 0 };
+        }
+
+        if entry.used >= budget.requests {
+            return Err(Self::status(budget, Some(entry), now));
+        }
+
+        entry.used += 1;
+        Ok(Self::status(budget, Some(entry), now))
+    }
+
+    /// Gives a tenant its whole budget back.
+    pub fn reset(&self, tenant: &str) {
+        self.lock().remove(tenant);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Usage>> {
+        self.usage.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn status(budget: Budget, usage: Option<&Usage>, now: Instant) -> BudgetStatus {
+        match usage.filter(|usage| now.duration_since(usage.since) < budget.window) {
+            Some(usage) => BudgetStatus {
+                limit: budget.requests,
+                remaining: budget.requests.saturating_sub(usage.used),
+                resets_in: budget.window - now.duration_since(usage.since),
+            },
+            None => BudgetStatus {
+                limit: budget.requests,
+                remaining: budget.requests,
+                resets_in: budget.window,
+            },
+        }
+    }
+}
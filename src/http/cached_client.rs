@@ -1,5 +1,7 @@
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Display;
+use std::future::Future;
 use std::marker::PhantomData;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -7,17 +9,28 @@ use std::time::Duration;
 use async_lock::Mutex;
 use bytes::{Buf, Bytes};
 use futures::FutureExt;
+use futures::future::OptionFuture;
+use futures::stream::{FuturesUnordered, Stream, StreamExt};
 use futures_core::future::BoxFuture;
 use http::{HeaderValue, Request};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use url::Url;
+use super::batch::{dedupe_users, BatchProgress, ProgressTracker};
+use super::profile::{PartFailure, Profile, ProfileOptions, ProfilePart};
+use super::watch::{self, UserEvent, WatchOptions};
+use super::budgets::{BudgetStatus, OnExhausted, TenantBudgets};
 use super::caches::cache::CacheHandler;
+use super::rate_limiters::{local::LocalRateLimiter, priority::{Priority, PriorityQueue}, rate_limiter::RateLimiter};
+use super::news::{CursorStore, NewsSubscriber};
+use super::session::Session;
 use super::error::{Error, ErrorTrait};
 use super::clients::http_client::HttpClient;
-use super::parameters::leaderboard_query::LeaderboardType;
-use super::parameters::personal_user_records::{PersonalLeaderboard, PersonalRecordsQuery};
+use super::parameters::leaderboard_query::{HistoricalLeaderboardType, LeaderboardType};
+use super::parameters::personal_user_records::{PersonalLeaderboard, PersonalRecordsQuery, ScoreFlowMode};
 use super::parameters::value_bound_query::ValueBoundQuery;
+use crate::models::common::APIint;
+use crate::models::general::achievement_catalog::AchievementCatalog;
 use crate::models::general::achivement_info::AchievementInfoPacket;
 use crate::models::general::activity::ActivityPacket;
 use crate::models::general::stats::StatsPacket;
@@ -27,19 +40,29 @@ use crate::models::labs::scoreflow::ScoreFlowPacket;
 use crate::models::news::latest::LatestNewsPacket;
 use crate::models::news::NewsPacket;
 use crate::models::packet::{Packet, SuccessPacket};
-use crate::models::users::summaries::{AchievementsSummaryPacket, AllSummariesPacket, BlitzSummaryPacket, LeagueSummaryPacket, SprintSummaryPacket, ZenSummaryPacket, ZenithExSummaryPacket, ZenithSummaryPacket};
+use crate::models::season::Season;
+use crate::models::users::summaries::{AchievementsSummaryPacket, AllSummaries, AllSummariesPacket, BlitzSummaryPacket, LeagueSummaryPacket, SprintSummaryPacket, ZenSummaryPacket, ZenithExSummaryPacket, ZenithSummaryPacket};
 use crate::models::users::user_history_leaderboard::HistoricalLeaderboardPacket;
 use crate::models::users::user_info::UserInfoPacket;
 use crate::models::users::user_leaderboard::LeaderboardPacket;
-use crate::models::users::user_records::{PersonalBlitzRecordPacket, PersonalLeagueRecordPacket, PersonalSprintRecordPacket, PersonalZenithExRecordPacket, PersonalZenithRecordPacket};
+use crate::models::users::user_records::{PersonalBlitzRecordPacket, PersonalLeagueRecordPacket, PersonalSprintRecordPacket, PersonalZenithExRecordPacket, PersonalZenithRecordPacket, RecordsLeaderboardPacket};
 use crate::models::users::user_search::UserSearchPacket;
 use futures::future::Either;
 use tower::Service;
 use tower_util::ServiceExt;
 
+/// How many achievements [`CachedClient::refresh_achievement_catalog`] requests at a time.
+pub const ACHIEVEMENT_CATALOG_CONCURRENCY: usize = 4;
+
+/// Everything but the priority and the tenant is shared with the handles returned by [`CachedClient::with_priority`] and [`CachedClient::for_tenant`].
 pub struct CachedClient<HttpClientImpl: HttpClient, Cache: CacheHandler<HttpClientImpl::HttpError>> {
-    req_service: Mutex<Box<dyn Send + Sync + Service< Request<Vec<u8>>, Response = Bytes, Error = HttpClientImpl::HttpError, Future = BoxFuture<'static, Result<Bytes, HttpClientImpl::HttpError>>>>>,
-    cache_handler: Cache,
+    req_service: Arc<Mutex<Box<dyn Send + Sync + Service< Request<Vec<u8>>, Response = Bytes, Error = HttpClientImpl::HttpError, Future = BoxFuture<'static, Result<Bytes, HttpClientImpl::HttpError>>>>>>,
+    cache_handler: Arc<Cache>,
+    rate_limiter: Arc<dyn RateLimiter>,
+    queue: Arc<PriorityQueue>,
+    priority: Priority,
+    budgets: Option<Arc<TenantBudgets>>,
+    tenant: Option<String>,
     _phantom: PhantomData<HttpClientImpl>,
 }
 
@@ -51,10 +74,14 @@ impl<HttpClientImpl: HttpClient + Default, Cache: CacheHandler<HttpClientImpl::H
 
 
 impl<HttpClientImpl: HttpClient, Cache: CacheHandler<HttpClientImpl::HttpError>> CachedClient<HttpClientImpl, Cache> {
+    /// Creates a client allowing 1 request every second, see [`LocalRateLimiter`].
     pub fn new(client: HttpClientImpl, cache_handler: Cache) -> Self {
+        Self::with_rate_limiter(client, cache_handler, LocalRateLimiter::default())
+    }
+
+    pub fn with_rate_limiter(client: HttpClientImpl, cache_handler: Cache, rate_limiter: impl RateLimiter) -> Self {
             let client = Arc::new(client);
             let svc = tower::ServiceBuilder::new()
-                .rate_limit(1, Duration::new(1, 0)) // 1 requests every 1 seconds
                 .service(tower::service_fn(move |request| {
                     let clone_client = client.clone();
                     async move {
@@ -63,12 +90,87 @@ impl<HttpClientImpl: HttpClient, Cache: CacheHandler<HttpClientImpl::HttpError>>
                 }));
 
             CachedClient {
-                req_service: Mutex::new(Box::new(svc)),
-                cache_handler,
+                req_service: Arc::new(Mutex::new(Box::new(svc))),
+                cache_handler: Arc::new(cache_handler),
+                rate_limiter: Arc::new(rate_limiter),
+                queue: Arc::new(PriorityQueue::default()),
+                priority: Priority::default(),
+                budgets: None,
+                tenant: None,
                 _phantom: PhantomData::default(),
             }
     }
 
+    /// Sets how long a request has to wait before it is promoted to the next priority, see [`PriorityQueue`].
+    pub fn with_priority_aging(mut self, aging: Duration) -> Self {
+        self.queue = Arc::new(PriorityQueue::new(aging));
+        self
+    }
+
+    /// A handle making its requests with the given priority.
+    /// It shares the cache, the rate limiter and the queue of this client, so requests made through it are ordered with every other one.
+    pub fn with_priority(&self, priority: Priority) -> Self {
+        CachedClient { priority, ..self.handle() }
+    }
+
+    /// Limits the requests made by each tenant, see [`TenantBudgets`].
+    pub fn with_budgets(mut self, budgets: TenantBudgets) -> Self {
+        self.budgets = Some(Arc::new(budgets));
+        self
+    }
+
+    /// A handle whose requests are counted against the budget of the given tenant.
+    /// Requests made without a tenant are not limited by the budgets.
+    pub fn for_tenant(&self, tenant: impl Into<String>) -> Self {
+        CachedClient { tenant: Some(tenant.into()), ..self.handle() }
+    }
+
+    pub fn tenant(&self) -> Option<&str> {
+        self.tenant.as_deref()
+    }
+
+    /// The budget this handle's tenant has left, None if its requests are not limited.
+    pub fn remaining_budget(&self) -> Option<BudgetStatus> {
+        Some(self.budgets.as_ref()?.remaining(self.tenant.as_deref()?))
+    }
+
+    fn handle(&self) -> Self {
+        CachedClient {
+            req_service: self.req_service.clone(),
+            cache_handler: self.cache_handler.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            queue: self.queue.clone(),
+            priority: self.priority,
+            budgets: self.budgets.clone(),
+            tenant: self.tenant.clone(),
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    /// The amount of requests waiting for their turn to be sent.
+    pub fn queued_requests(&self) -> usize {
+        self.queue.waiting()
+    }
+
+    /// Starts a new session with a freshly generated ID, see [`Session`].
+    pub fn session(&self) -> Session<'_, HttpClientImpl, Cache> {
+        Session::new(self)
+    }
+
+    /// Resumes a session using a known ID, see [`Session`].
+    pub fn session_with_id(&self, id: impl Into<String>) -> Session<'_, HttpClientImpl, Cache> {
+        Session::with_id(self, id)
+    }
+
+    /// Starts a news subscriber keeping its cursors in the given store, see [`NewsSubscriber`].
+    pub fn news_subscriber<Store: CursorStore>(&self, store: Store) -> NewsSubscriber<'_, HttpClientImpl, Cache, Store> {
+        NewsSubscriber::new(self, store)
+    }
+
 }
 
 
@@ -130,9 +232,17 @@ impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImp
         };
         
 
-        let mut service = self.req_service.lock().await;
+        let request = req.body(vec![]).map_err(Error::RequestParsingError)?;
+
+        self.queue.acquire(self.priority, self.rate_limiter.as_ref()).await;
+
+        // The returned future owns everything it needs, so the response is awaited without blocking the next requests.
+        let response = {
+            let mut service = self.req_service.lock().await;
+            service.ready_and().await.map_err(Error::HttpError)?.call(request)
+        };
 
-        let response = service.ready_and().await.map_err(Error::HttpError)?.call(req.body(vec![]).map_err(Error::RequestParsingError)?).await.map_err(Error::HttpError)?;
+        let response = response.await.map_err(Error::HttpError)?;
 
         
 
@@ -153,6 +263,13 @@ impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImp
         }
     }
 
+    /// Uses one request of the tenant's budget, returns the exhausted budget if there was nothing left.
+    fn spend_budget(&self) -> Option<BudgetStatus> {
+        let (budgets, tenant) = (self.budgets.as_ref()?, self.tenant.as_deref()?);
+
+        budgets.try_spend(tenant).err()
+    }
+
     pub(crate) fn get_url(route: impl Display) -> String {
         format!("{TETRIO_API_URL}{route}")
     }
@@ -167,6 +284,18 @@ impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImp
         dbg!(&url);
         let response = self.cache_handler.try_get_cache(&cache_key).await?;
         response.map_or_else(|| Either::Left(async {
+            if let Some(exhausted) = self.spend_budget() {
+                return match self.budgets.as_ref().map(|budgets| budgets.on_exhausted()) {
+                    Some(OnExhausted::CacheOnly) => Ok(Packet {
+                        success: false,
+                        cache: None,
+                        data: None,
+                        error: Some(crate::models::packet::Error { msg: format!("Request budget exhausted: {exhausted}") }),
+                    }),
+                    _ => Err(Error::BudgetExhausted(exhausted)),
+                };
+            }
+
             let packet = self.make_request::<Packet<T>>(&url, &session_id).await;
             dbg!(packet.is_ok());
             // ignore error because we don't care if it's not cached
@@ -319,9 +448,122 @@ impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImp
 
 
 
+    /// Also caches every summary on its own, so the per-mode requests for this user are served from the cache until the same expiry.
     pub async fn fetch_user_summaries(&self, user: &str) -> Result<AllSummariesPacket, <Self as ErrorTrait>::Error> {
-        self.make_tetrio_api_request(format!("users/{user}/summaries"), None).await
+        let route = format!("users/{user}/summaries");
+        if let Some(packet) = self.get_from_cache(&route, None).await? {
+            return Ok(packet);
+        }
+
+        self.request_user_summaries(user).await
+    }
+
+    /// Requests the summaries of every mode from the api, then caches every summary on its own.
+    /// Every summaries response goes through here, whether it was requested alone or in a batch.
+    async fn request_user_summaries(&self, user: &str) -> Result<AllSummariesPacket, <Self as ErrorTrait>::Error> {
+        let packet: AllSummariesPacket = self.make_tetrio_api_request(format!("users/{user}/summaries"), None).await?;
+        if let (true, Some(cache), Some(summaries)) = (packet.success, &packet.cache, &packet.data) {
+            // ignore error because we don't care if it's not cached
+            let _ = self.cache_summary_sections(user, cache, summaries).await;
+        }
+
+        Ok(packet)
+    }
+
+    async fn cache_summary_sections(&self, user: &str, cache: &crate::models::cache::Cache, summaries: &AllSummaries) -> Result<(), <Self as ErrorTrait>::Error> {
+        self.cache_summary_section(user, "40l", cache, &summaries.sprint).await?;
+        self.cache_summary_section(user, "blitz", cache, &summaries.blitz).await?;
+        self.cache_summary_section(user, "zenith", cache, &summaries.zenith).await?;
+        self.cache_summary_section(user, "zenithex", cache, &summaries.zenithex).await?;
+        self.cache_summary_section(user, "league", cache, &summaries.league).await?;
+        self.cache_summary_section(user, "zen", cache, &summaries.zen).await?;
+        self.cache_summary_section(user, "achievements", cache, &summaries.achievements).await
+    }
+
+    async fn cache_summary_section<T: DeserializeOwned + Serialize + Send + Sync + Clone>(&self, user: &str, mode: &str, cache: &crate::models::cache::Cache, summary: &T) -> Result<(), <Self as ErrorTrait>::Error> {
+        let cache_key = Self::get_cache_key(&Self::get_url(format!("users/{user}/summaries/{mode}")), &None);
+
+        self.cache_handler.cache_value(&cache_key, SuccessPacket { success: true, cache: cache.clone(), data: summary.clone() }).await
+    }
 
+    /// Fetches a single summary, taking it from the cached summaries of every mode if they are cached.
+    async fn fetch_user_summary<T: DeserializeOwned + Serialize + Send + Sync + Clone>(&self, user: &str, mode: &str, section: impl FnOnce(AllSummaries) -> T) -> Result<Packet<T>, <Self as ErrorTrait>::Error> {
+        let route = format!("users/{user}/summaries/{mode}");
+        if let Some(packet) = self.get_from_cache(&route, None).await? {
+            return Ok(packet);
+        }
+
+        if let Some(Packet { success: true, cache, data: Some(summaries), .. }) = self.get_from_cache::<AllSummaries>(format!("users/{user}/summaries"), None).await? {
+            return Ok(Packet { success: true, cache, data: Some(section(summaries)), error: None });
+        }
+
+        self.make_tetrio_api_request(route, None).await
+    }
+
+    /// Fetches the info of many users, see [`CachedClient::fetch_users`].
+    pub async fn fetch_users_info<'a>(&self,
+                                      users: impl IntoIterator<Item = &'a str>,
+                                      on_progress: impl FnMut(BatchProgress)) -> BTreeMap<String, Result<UserInfoPacket, <Self as ErrorTrait>::Error>> {
+        self.fetch_users(users, |user| format!("users/{user}"), on_progress).await
+    }
+
+    /// Fetches the summaries of many users, see [`CachedClient::fetch_users`].
+    pub async fn fetch_users_summaries<'a>(&self,
+                                           users: impl IntoIterator<Item = &'a str>,
+                                           on_progress: impl FnMut(BatchProgress)) -> BTreeMap<String, Result<AllSummariesPacket, <Self as ErrorTrait>::Error>> {
+        self.fetch_users_with(users, |user| format!("users/{user}/summaries"), |user, _| async move { self.request_user_summaries(&user).await }, on_progress).await
+    }
+
+    /// Makes the same request for many users, returning the result of each user keyed by its lowercased reference.
+    ///
+    /// Duplicated users are only requested once. Users found in the cache are served right away,
+    /// the other requests are all started together and go through the rate limiter like any other request.
+    /// A failed request only fails the result of its user.
+    /// `on_progress` is called every time a user is done; to report progress through a channel, send from it.
+    pub async fn fetch_users<'a, T: DeserializeOwned + Serialize + Send + Sync + Clone>(&self,
+                                      users: impl IntoIterator<Item = &'a str>,
+                                      route: impl Fn(&str) -> String,
+                                      on_progress: impl FnMut(BatchProgress)) -> BTreeMap<String, Result<Packet<T>, <Self as ErrorTrait>::Error>> {
+        self.fetch_users_with(users, route, |_, route| self.make_tetrio_api_request::<T>(route, None), on_progress).await
+    }
+
+    /// [`CachedClient::fetch_users`], requesting the users missing from the cache with `fetch`, which is given the user and its route.
+    async fn fetch_users_with<'a, T: DeserializeOwned + Serialize + Send + Sync + Clone, F: Future<Output = Result<Packet<T>, <Self as ErrorTrait>::Error>>>(&self,
+                                      users: impl IntoIterator<Item = &'a str>,
+                                      route: impl Fn(&str) -> String,
+                                      fetch: impl Fn(String, String) -> F,
+                                      mut on_progress: impl FnMut(BatchProgress)) -> BTreeMap<String, Result<Packet<T>, <Self as ErrorTrait>::Error>> {
+        let users = dedupe_users(users);
+        let mut progress = ProgressTracker::new(users.len());
+        let mut results = BTreeMap::new();
+        let mut misses = vec![];
+
+        for user in users {
+            // Errors reading the cache are reported by the request made for the user instead.
+            match self.get_from_cache::<T>(route(&user), None).await {
+                Ok(Some(packet)) => {
+                    on_progress(progress.cached(packet.is_success()));
+                    results.insert(user, Ok(packet));
+                }
+                _ => misses.push(user),
+            }
+        }
+
+        progress.start_fetching();
+        let mut pending = misses
+            .into_iter()
+            .map(|user| {
+                let request = fetch(user.clone(), route(&user));
+                async move { (user, request.await) }
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        while let Some((user, result)) = pending.next().await {
+            on_progress(progress.fetched(result.as_ref().is_ok_and(Packet::is_success)));
+            results.insert(user, result);
+        }
+
+        results
     }
 
 
@@ -359,7 +601,7 @@ impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImp
 
 
     pub async fn fetch_user_zen_summaries(&self, user: &str) -> Result<ZenSummaryPacket, <Self as ErrorTrait>::Error> {
-        self.make_tetrio_api_request(format!("users/{user}/summaries/zen"), None).await
+        self.fetch_user_summary(user, "zen", |summaries| summaries.zen).await
 
     }
 
@@ -398,7 +640,7 @@ impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImp
 
 
     pub async fn fetch_user_league_summaries(&self, user: &str) -> Result<LeagueSummaryPacket, <Self as ErrorTrait>::Error> {
-        self.make_tetrio_api_request(format!("users/{user}/summaries/league"), None).await
+        self.fetch_user_summary(user, "league", |summaries| summaries.league).await
 
     }
 
@@ -437,7 +679,7 @@ impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImp
 
 
     pub async fn fetch_user_40l_summaries(&self, user: &str) -> Result<SprintSummaryPacket, <Self as ErrorTrait>::Error> {
-        self.make_tetrio_api_request(format!("users/{user}/summaries/40l"), None).await
+        self.fetch_user_summary(user, "40l", |summaries| summaries.sprint).await
 
     }
 
@@ -477,7 +719,7 @@ impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImp
 
 
     pub async fn fetch_user_blitz_summaries(&self, user: &str) -> Result<BlitzSummaryPacket, <Self as ErrorTrait>::Error> {
-        self.make_tetrio_api_request(format!("users/{user}/summaries/blitz"), None).await
+        self.fetch_user_summary(user, "blitz", |summaries| summaries.blitz).await
 
     }
 
@@ -516,7 +758,7 @@ impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImp
 
 
     pub async fn fetch_user_zenith_summaries(&self, user: &str) -> Result<ZenithSummaryPacket, <Self as ErrorTrait>::Error> {
-        self.make_tetrio_api_request(format!("users/{user}/summaries/zenith"), None).await
+        self.fetch_user_summary(user, "zenith", |summaries| summaries.zenith).await
 
     }
 
@@ -555,7 +797,7 @@ impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImp
 
 
     pub async fn fetch_user_zenithex_summaries(&self, user: &str) -> Result<ZenithExSummaryPacket, <Self as ErrorTrait>::Error> {
-        self.make_tetrio_api_request(format!("users/{user}/summaries/zenithex"), None).await
+        self.fetch_user_summary(user, "zenithex", |summaries| summaries.zenithex).await
 
     }
 
@@ -594,7 +836,7 @@ impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImp
 
 
     pub async fn fetch_user_achievements_summaries(&self, user: &str) -> Result<AchievementsSummaryPacket, <Self as ErrorTrait>::Error> {
-        self.make_tetrio_api_request(format!("users/{user}/summaries/achievements"), None).await
+        self.fetch_user_summary(user, "achievements", |summaries| summaries.achievements).await
     }
     
     
@@ -641,57 +883,68 @@ impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImp
     }
 
     pub async fn fetch_historical_leaderboard(&self,
-                                   leaderboard_type: LeaderboardType,
-                                   season: String,
+                                   leaderboard_type: HistoricalLeaderboardType,
+                                   season: &Season,
                                    query: ValueBoundQuery,
                                    session_id: Option<&str>) -> Result<HistoricalLeaderboardPacket, <Self as ErrorTrait>::Error> {
         let url = format!("users/history/{}/{}", leaderboard_type.to_string(), season);
         self.make_tetrio_api_request(Self::make_url(&url, &query.as_query_params()), session_id).await
     }
 
+    /// Fetches a page of a records leaderboard, like `40l_global`, `blitz_country_JP` or `zenith_global@2024w31`.
+    /// The type of the records depends on the game mode of the leaderboard (e.g. [`SprintRecord`](crate::models::users::user_records::SprintRecord) for `40l_*`).
+    pub async fn fetch_records_leaderboard<T: DeserializeOwned + Serialize + Send + Sync + Clone>(&self,
+                                   leaderboard: &str,
+                                   query: ValueBoundQuery,
+                                   session_id: Option<&str>) -> Result<RecordsLeaderboardPacket<T>, <Self as ErrorTrait>::Error> {
+        let url = format!("records/{leaderboard}");
+        self.make_tetrio_api_request(Self::make_url(&url, &query.as_query_params()), session_id).await
+    }
+
+    pub(crate) async fn fetch_user_personal_records<T: DeserializeOwned + Serialize + Send + Sync + Clone>(&self,
+                                             user: &str,
+                                             game_mode: &str,
+                                             leaderboard: PersonalLeaderboard,
+                                             query: PersonalRecordsQuery,
+                                             session_id: Option<&str>) -> Result<Packet<T>, <Self as ErrorTrait>::Error> {
+        let url = format!("users/{}/records/{}/{}", user, game_mode, leaderboard.to_string());
+
+        self.make_tetrio_api_request(Self::make_url(&url, &query.as_query_params()), session_id).await
+    }
+
     pub async fn fetch_user_personal_40l_records(&self,
                                              user: &str,
                                              leaderboard: PersonalLeaderboard,
                                              query: PersonalRecordsQuery) -> Result<PersonalSprintRecordPacket, <Self as ErrorTrait>::Error> {
-        let url = format!("users/{}/records/{}/{}", user, "40l", leaderboard.to_string());
-
-        self.make_tetrio_api_request(Self::make_url(&url, &query.as_query_params()), None).await
+        self.fetch_user_personal_records(user, "40l", leaderboard, query, None).await
     }
 
     pub async fn fetch_user_personal_blitz_records(&self,
                                              user: &str,
                                              leaderboard: PersonalLeaderboard,
                                              query: PersonalRecordsQuery) -> Result<PersonalBlitzRecordPacket, <Self as ErrorTrait>::Error> {
-        let url = format!("users/{}/records/{}/{}", user, "blitz", leaderboard.to_string());
-
-        self.make_tetrio_api_request(Self::make_url(&url, &query.as_query_params()), None).await
+        self.fetch_user_personal_records(user, "blitz", leaderboard, query, None).await
     }
 
     pub async fn fetch_user_personal_league_records(&self,
                                              user: &str,
                                              leaderboard: PersonalLeaderboard,
                                              query: PersonalRecordsQuery) -> Result<PersonalLeagueRecordPacket, <Self as ErrorTrait>::Error> {
-        let url = format!("users/{}/records/{}/{}", user, "league", leaderboard.to_string());
-
-        self.make_tetrio_api_request(Self::make_url(&url, &query.as_query_params()), None).await
+        self.fetch_user_personal_records(user, "league", leaderboard, query, None).await
     }
 
     pub async fn fetch_user_personal_zenith_records(&self,
                                              user: &str,
                                              leaderboard: PersonalLeaderboard,
                                              query: PersonalRecordsQuery) -> Result<PersonalZenithRecordPacket, <Self as ErrorTrait>::Error> {
-        let url = format!("users/{}/records/{}/{}", user, "zenith", leaderboard.to_string());
-
-        self.make_tetrio_api_request(Self::make_url(&url, &query.as_query_params()), None).await
+        self.fetch_user_personal_records(user, "zenith", leaderboard, query, None).await
     }
 
     pub async fn fetch_user_personal_zenithex_records(&self,
                                              user: &str,
                                              leaderboard: PersonalLeaderboard,
                                              query: PersonalRecordsQuery) -> Result<PersonalZenithExRecordPacket, <Self as ErrorTrait>::Error> {
-        let url = format!("users/{}/records/{}/{}", user, "zenithex", leaderboard.to_string());
-
-        self.make_tetrio_api_request(Self::make_url(&url, &query.as_query_params()), None).await
+        self.fetch_user_personal_records(user, "zenithex", leaderboard, query, None).await
     }
     
     pub async fn fetch_news(&self, limit: Option<i64>) -> Result<NewsPacket, <Self as ErrorTrait>::Error> {
@@ -711,7 +964,8 @@ impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImp
         self.make_tetrio_api_request(Self::make_url(&url, &limit), None).await
     }
     
-    pub async fn fetch_scoreflow(&self, user: &str, game_mode: &str) -> Result<ScoreFlowPacket, <Self as ErrorTrait>::Error> {
+    /// Only 40 LINES and BLITZ have a scoreflow, a [`GameMode`](super::parameters::personal_user_records::GameMode) can be converted with `ScoreFlowMode::try_from`.
+    pub async fn fetch_scoreflow(&self, user: &str, game_mode: ScoreFlowMode) -> Result<ScoreFlowPacket, <Self as ErrorTrait>::Error> {
         let url = format!("labs/scoreflow/{user}/{game_mode}");
         self.make_tetrio_api_request(url, None).await
     }
@@ -721,6 +975,46 @@ impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImp
         self.make_tetrio_api_request(url, None).await
     }
 
+    /// Polls a user's info and summaries every `interval`, emitting the changes found between two polls, see [`UserEvent`].
+    /// A poll never happens before the data of the previous one expires, and the first poll only records the state of the user.
+    /// Failed polls emit an error, and the stream keeps polling.
+    pub fn watch_user<'a>(&'a self, user: &str, interval: Duration) -> impl Stream<Item = Result<UserEvent, PartFailure<<Self as ErrorTrait>::Error>>> + 'a {
+        self.watch_user_with(user, WatchOptions::new(interval))
+    }
+
+    pub fn watch_user_with<'a>(&'a self, user: &str, options: WatchOptions) -> impl Stream<Item = Result<UserEvent, PartFailure<<Self as ErrorTrait>::Error>>> + 'a {
+        watch::watch_user(self, user, options)
+    }
+
+    /// Fetches the info, summaries, recent league records and optionally the leagueflow of a user, see [`Profile`].
+    /// The requests are made together, so they go through the rate limiter one after the other without waiting on each other's responses.
+    /// A failed request only leaves its part of the profile empty.
+    pub async fn fetch_profile(&self, user: &str, options: ProfileOptions) -> Profile<<Self as ErrorTrait>::Error> {
+        let league_records = options.league_records.map(|limit| {
+            self.fetch_user_personal_league_records(user, PersonalLeaderboard::Recent, PersonalRecordsQuery::NotBound { limit: Some(limit) })
+        });
+        let leagueflow = options.leagueflow.then(|| self.fetch_leagueflow(user));
+
+        let (info, summaries, league_records, leagueflow) = futures::join!(
+            self.fetch_user_info(user),
+            self.fetch_user_summaries(user),
+            OptionFuture::from(league_records),
+            OptionFuture::from(leagueflow),
+        );
+
+        let mut profile = Profile::new();
+        profile.info = profile.take(ProfilePart::Info, info);
+        profile.summaries = profile.take(ProfilePart::Summaries, summaries);
+        if let Some(league_records) = league_records {
+            profile.league_records = profile.take(ProfilePart::LeagueRecords, league_records).map(|records| records.entries);
+        }
+        if let Some(leagueflow) = leagueflow {
+            profile.leagueflow = profile.take(ProfilePart::LeagueFlow, leagueflow);
+        }
+
+        profile
+    }
+
     pub async fn fetch_leagueranks(&self) -> Result<LeagueRanksPacket, <Self as ErrorTrait>::Error> {
         let url = format!("labs/league_ranks");
         self.make_tetrio_api_request(url, None).await
@@ -730,5 +1024,41 @@ impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImp
         let url = format!("achievements/{achievement}");
         self.make_tetrio_api_request(url, None).await
     }
+
+    /// Fetches the definition of every given achievement ID, see [`CachedClient::refresh_achievement_catalog`].
+    pub async fn fetch_achievement_catalog(&self, ids: impl IntoIterator<Item = APIint>) -> (AchievementCatalog, BTreeMap<APIint, PartFailure<<Self as ErrorTrait>::Error>>) {
+        let mut catalog = AchievementCatalog::new();
+        let failures = self.refresh_achievement_catalog(&mut catalog, ids).await;
+        (catalog, failures)
+    }
+
+    /// Fetches the definition of every given achievement ID, replacing the ones already in the catalog,
+    /// with at most [`ACHIEVEMENT_CATALOG_CONCURRENCY`] requests at a time.
+    /// To only fetch the achievements which are not part of the catalog yet, pass [`AchievementCatalog::missing_ids`].
+    ///
+    /// A failed request only fails its achievement, which keeps its previous definition.
+    /// Returns why each achievement could not be fetched, keyed by ID.
+    pub async fn refresh_achievement_catalog(&self, catalog: &mut AchievementCatalog, ids: impl IntoIterator<Item = APIint>) -> BTreeMap<APIint, PartFailure<<Self as ErrorTrait>::Error>> {
+        let ids = ids.into_iter().collect::<BTreeSet<_>>();
+        let mut failures = BTreeMap::new();
+
+        let mut responses = futures::stream::iter(ids)
+            .map(|id| async move { (id, self.fetch_achievement_info(&id.to_string()).await) })
+            .buffer_unordered(ACHIEVEMENT_CATALOG_CONCURRENCY);
+
+        while let Some((id, response)) = responses.next().await {
+            match response {
+                Ok(Packet { data: Some(info), .. }) => catalog.insert(info),
+                Ok(packet) => {
+                    failures.insert(id, PartFailure::Unsuccessful(packet.error.map(|error| error.msg)));
+                }
+                Err(error) => {
+                    failures.insert(id, PartFailure::Request(error));
+                }
+            }
+        }
+
+        failures
+    }
 }
 
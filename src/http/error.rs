@@ -1,5 +1,7 @@
 use std::fmt::{Debug, Display};
 
+use super::budgets::BudgetStatus;
+
 
 #[derive(Debug)]
 pub enum Error<HttpError: Debug + Send + Sync, CachingError: Debug + Send + Sync> {
@@ -11,6 +13,8 @@ pub enum Error<HttpError: Debug + Send + Sync, CachingError: Debug + Send + Sync
     ParsingError(serde_path_to_error::Error<serde_json::Error>),
     ConversionError(serde_path_to_error::Error<serde_json::Error>),
     SerdeError(serde_json::Error),
+    /// The tenant used up its request budget, see [`TenantBudgets`](super::budgets::TenantBudgets).
+    BudgetExhausted(BudgetStatus),
 }
 
 
@@ -28,6 +32,7 @@ impl<HttpError: StdError + Debug + Send + Sync, CachingError: StdError + Debug +
             Error::HttpError(error) => write!(f, "HttpError: {error}"),
             Error::CachingError(error) => write!(f, "CachingError: {error}"),
             Error::SerdeError(error) => write!(f, "SerdeError: {error}"),
+            Error::BudgetExhausted(status) => write!(f, "BudgetExhausted: {status}"),
         }
     }
 }
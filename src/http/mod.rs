@@ -1,8 +1,29 @@
 
 pub mod cached_client;
 
+/// X-SESSION-ID handles keeping paginated requests consistent.
+pub mod session;
+
 pub mod error;
 pub mod clients;
 pub mod caches;
 
+/// Rate limiters deciding when requests to the API may start.
+pub mod rate_limiters;
+
+/// Per-tenant request budgets for clients shared by many callers.
+pub mod budgets;
+
+/// Progress reporting for requests made for many users at once.
+pub mod batch;
+
+/// Everything a profile command shows about a user, fetched in one call.
+pub mod profile;
+
+/// Streams of the changes happening to a user, found by polling it.
+pub mod watch;
+
+/// News subscribers remembering the last item they saw in each stream.
+pub mod news;
+
 pub mod parameters;
\ No newline at end of file
@@ -0,0 +1,259 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{Debug, Display};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+
+use super::cached_client::CachedClient;
+use super::caches::cache::CacheHandler;
+use super::clients::http_client::HttpClient;
+use super::error::ErrorTrait;
+use super::profile::PartFailure;
+use crate::models::common::{APIstring, APItimestamp};
+use crate::models::news::latest::LatestNews;
+
+/// The newest news item seen in a stream.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NewsCursor {
+    pub id: APIstring,
+    pub ts: APItimestamp,
+}
+
+impl NewsCursor {
+    pub fn of(news: &LatestNews) -> Self {
+        Self { id: news.id.clone(), ts: news.ts.clone() }
+    }
+
+    /// Whether a news item came after this cursor. Items are ordered by date, then by ID.
+    pub fn is_before(&self, news: &LatestNews) -> bool {
+        (&self.ts, &self.id) < (&news.ts, &news.id)
+    }
+}
+
+/// Where news subscribers keep the cursor of each stream, so a restarted subscriber doesn't announce the same news again.
+#[async_trait]
+pub trait CursorStore: Send + Sync {
+    async fn load(&self, stream: &str) -> std::io::Result<Option<NewsCursor>>;
+
+    async fn save(&self, stream: &str, cursor: &NewsCursor) -> std::io::Result<()>;
+}
+
+/// Keeps the cursors in memory, they are lost when the process stops.
+#[derive(Default)]
+pub struct MemoryCursorStore {
+    cursors: Mutex<HashMap<String, NewsCursor>>,
+}
+
+#[async_trait]
+impl CursorStore for MemoryCursorStore {
+    async fn load(&self, stream: &str) -> std::io::Result<Option<NewsCursor>> {
+        Ok(self.cursors.lock().unwrap_or_else(|err| err.into_inner()).get(stream).cloned())
+    }
+
+    async fn save(&self, stream: &str, cursor: &NewsCursor) -> std::io::Result<()> {
+        self.cursors.lock().unwrap_or_else(|err| err.into_inner()).insert(stream.to_string(), cursor.clone());
+        Ok(())
+    }
+}
+
+/// Keeps the cursors of every stream in a JSON file.
+pub struct FileCursorStore {
+    path: PathBuf,
+    /* Serializes the writes of this process. */
+    lock: tokio::sync::Mutex<()>,
+}
+
+impl FileCursorStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), lock: tokio::sync::Mutex::new(()) }
+    }
+
+    async fn read(&self) -> std::io::Result<HashMap<String, NewsCursor>> {
+        match tokio::fs::read(&self.path).await {
+            Ok(content) => serde_json::from_slice(&content).map_err(std::io::Error::other),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(HashMap::new()),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[async_trait]
+impl CursorStore for FileCursorStore {
+    async fn load(&self, stream: &str) -> std::io::Result<Option<NewsCursor>> {
+        Ok(self.read().await?.remove(stream))
+    }
+
+    async fn save(&self, stream: &str, cursor: &NewsCursor) -> std::io::Result<()> {
+        let _guard = self.lock.lock().await;
+        let mut cursors = self.read().await?;
+        cursors.insert(stream.to_string(), cursor.clone());
+
+        // Written next to the file then renamed, so a crash never leaves a partially written file.
+        let temporary = self.path.with_extension("tmp");
+        tokio::fs::write(&temporary, serde_json::to_vec_pretty(&cursors).map_err(std::io::Error::other)?).await?;
+        tokio::fs::rename(temporary, &self.path).await
+    }
+}
+
+/// Why a news subscriber could not poll a stream.
+#[derive(Debug)]
+pub enum NewsError<E> {
+    /// The news could not be fetched.
+    Fetch(PartFailure<E>),
+    Store(std::io::Error),
+}
+
+impl<E: Display> Display for NewsError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NewsError::Fetch(failure) => write!(f, "{failure}"),
+            NewsError::Store(error) => write!(f, "Couldn't access the news cursor: {error}"),
+        }
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for NewsError<E> {}
+
+/// The new items of every stream polled, and the streams which could not be polled.
+#[derive(Debug)]
+pub struct NewsPoll<E> {
+    pub news: Vec<LatestNews>,
+    pub failures: Vec<(String, NewsError<E>)>,
+}
+
+type SubscriberError<HttpClientImpl, Cache> = NewsError<<CachedClient<HttpClientImpl, Cache> as ErrorTrait>::Error>;
+
+/// Polls news streams and only returns the items which came after the last ones it saw.
+///
+/// The cursor of a stream is saved before its new items are returned, so an item is never returned twice,
+/// even if the process stops before the items were handled.
+/// The first time a stream is polled, its current items are only used to set the cursor, unless [`NewsSubscriber::with_backlog`] is used.
+pub struct NewsSubscriber<'a, HttpClientImpl: HttpClient, Cache: CacheHandler<HttpClientImpl::HttpError>, Store: CursorStore> {
+    client: &'a CachedClient<HttpClientImpl, Cache>,
+    store: Store,
+    streams: Vec<String>,
+    types: Option<HashSet<String>>,
+    limit: Option<i64>,
+    backlog: bool,
+}
+
+impl<'a, HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImpl::HttpError>, Store: CursorStore> NewsSubscriber<'a, HttpClientImpl, Cache, Store> {
+    pub fn new(client: &'a CachedClient<HttpClientImpl, Cache>, store: Store) -> Self {
+        Self {
+            client,
+            store,
+            streams: vec![],
+            types: None,
+            limit: None,
+            backlog: false,
+        }
+    }
+
+    /// Subscribes to a stream, like `global` or `user_{user_id}`.
+    pub fn stream(mut self, stream: impl Into<String>) -> Self {
+        let stream = stream.into();
+        if !self.streams.contains(&stream) {
+            self.streams.push(stream);
+        }
+        self
+    }
+
+    pub fn global(self) -> Self {
+        self.stream("global")
+    }
+
+    /// Subscribes to the news of a user, by its user ID.
+    pub fn user(self, user_id: &str) -> Self {
+        self.stream(format!("user_{user_id}"))
+    }
+
+    /// Only returns the news of the given types, like `personalbest` or `rankup`.
+    pub fn types<'t>(mut self, types: impl IntoIterator<Item = &'t str>) -> Self {
+        self.types = Some(types.into_iter().map(str::to_string).collect());
+        self
+    }
+
+    /// The amount of items fetched per stream and poll.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the items already in a stream the first time it is polled.
+    pub fn with_backlog(mut self) -> Self {
+        self.backlog = true;
+        self
+    }
+
+    pub fn streams(&self) -> &[String] {
+        &self.streams
+    }
+
+    /// Polls every stream, returning their new items from the oldest to the newest.
+    /// A failed stream does not stop the others from being polled.
+    pub async fn poll(&self) -> NewsPoll<<CachedClient<HttpClientImpl, Cache> as ErrorTrait>::Error> {
+        let mut poll = NewsPoll { news: vec![], failures: vec![] };
+
+        for stream in &self.streams {
+            match self.poll_stream(stream).await {
+                Ok(news) => poll.news.extend(news),
+                Err(error) => poll.failures.push((stream.clone(), error)),
+            }
+        }
+
+        poll.news.sort_by(|left, right| (&left.ts, &left.id).cmp(&(&right.ts, &right.id)));
+        poll
+    }
+
+    async fn poll_stream(&self, stream: &str) -> Result<Vec<LatestNews>, SubscriberError<HttpClientImpl, Cache>> {
+        let cursor = self.store.load(stream).await.map_err(NewsError::Store)?;
+
+        let packet = self.client.fetch_latest_news(stream, self.limit).await.map_err(|error| NewsError::Fetch(PartFailure::Request(error)))?;
+        let mut news = match packet.data {
+            Some(data) if packet.success => data.news,
+            _ => return Err(NewsError::Fetch(PartFailure::Unsuccessful(packet.error.map(|error| error.msg)))),
+        };
+
+        news.retain(|item| cursor.as_ref().is_none_or(|cursor| cursor.is_before(item)));
+        news.sort_by(|left, right| (&left.ts, &left.id).cmp(&(&right.ts, &right.id)));
+
+        if let Some(newest) = news.last() {
+            self.store.save(stream, &NewsCursor::of(newest)).await.map_err(NewsError::Store)?;
+        }
+
+        if cursor.is_none() && !self.backlog {
+            return Ok(vec![]);
+        }
+
+        news.retain(|item| self.types.as_ref().is_none_or(|types| types.contains(&item.item_type)));
+        Ok(news)
+    }
+
+    /// Polls the streams every `interval`, yielding their new items one by one.
+    /// Streams which could not be polled yield an error first, and the subscription keeps polling.
+    pub fn subscribe(self, interval: Duration) -> impl Stream<Item = Result<LatestNews, SubscriberError<HttpClientImpl, Cache>>> + 'a
+    where
+        Store: 'a,
+    {
+        futures::stream::unfold((self, VecDeque::new(), None), move |(subscriber, mut pending, mut next_poll)| async move {
+            loop {
+                if let Some(item) = pending.pop_front() {
+                    return Some((item, (subscriber, pending, next_poll)));
+                }
+
+                if let Some(next_poll) = next_poll {
+                    tokio::time::sleep_until(next_poll).await;
+                }
+                next_poll = Some(tokio::time::Instant::now() + interval);
+
+                let poll = subscriber.poll().await;
+                pending.extend(poll.failures.into_iter().map(|(_, error)| Err(error)));
+                pending.extend(poll.news.into_iter().map(Ok));
+            }
+        })
+    }
+}
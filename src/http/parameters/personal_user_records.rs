@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use super::value_bound_query::Prisecter;
 
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum GameMode {
     #[serde(rename = "40l")]
@@ -26,6 +26,46 @@ impl ToString for GameMode {
     }
 }
 
+/// The game modes which have a scoreflow.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum ScoreFlowMode {
+    #[serde(rename = "40l")]
+    Sprint,
+    Blitz,
+}
+
+impl std::fmt::Display for ScoreFlowMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sprint => f.write_str("40l"),
+            Self::Blitz => f.write_str("blitz"),
+        }
+    }
+}
+
+impl From<ScoreFlowMode> for GameMode {
+    fn from(value: ScoreFlowMode) -> Self {
+        match value {
+            ScoreFlowMode::Sprint => GameMode::Sprint,
+            ScoreFlowMode::Blitz => GameMode::Blitz,
+        }
+    }
+}
+
+impl TryFrom<GameMode> for ScoreFlowMode {
+    /// The game mode, which has no scoreflow.
+    type Error = GameMode;
+
+    fn try_from(value: GameMode) -> Result<Self, Self::Error> {
+        match value {
+            GameMode::Sprint => Ok(ScoreFlowMode::Sprint),
+            GameMode::Blitz => Ok(ScoreFlowMode::Blitz),
+            other => Err(other),
+        }
+    }
+}
+
 pub enum PersonalLeaderboard {
     Top,
     Recent,
@@ -0,0 +1,99 @@
+use std::fmt::Display;
+
+use crate::models::{
+    common::APIint,
+    labs::leagueflow::LeagueFlow,
+    packet::Packet,
+    users::{summaries::AllSummaries, user_info::UserInfo, user_records::LeagueRecord},
+};
+
+/// What to fetch along with the user info and summaries in [`CachedClient::fetch_profile`](super::cached_client::CachedClient::fetch_profile).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProfileOptions {
+    /* The amount of recent TETRA LEAGUE records to fetch, None to skip them. */
+    pub league_records: Option<APIint>,
+    pub leagueflow: bool,
+}
+
+impl Default for ProfileOptions {
+    /// The 10 most recent league records, without the leagueflow.
+    fn default() -> Self {
+        Self {
+            league_records: Some(10),
+            leagueflow: false,
+        }
+    }
+}
+
+/// The requests a profile is made of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProfilePart {
+    Info,
+    Summaries,
+    LeagueRecords,
+    LeagueFlow,
+}
+
+/// Why a request did not give any data, like a missing part of a profile.
+#[derive(Debug)]
+pub enum PartFailure<E> {
+    /// The request could not be made or its response could not be parsed.
+    Request(E),
+    /// The API answered with an unsuccessful packet, with its error message if it sent one.
+    Unsuccessful(Option<String>),
+}
+
+impl<E: Display> Display for PartFailure<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartFailure::Request(error) => write!(f, "{error}"),
+            PartFailure::Unsuccessful(Some(msg)) => write!(f, "{msg}"),
+            PartFailure::Unsuccessful(None) => write!(f, "The API did not return any data"),
+        }
+    }
+}
+
+/// Everything a profile command usually shows about a user.
+/// Parts which could not be fetched are None, and the reason is kept in `failures`.
+#[derive(Debug)]
+pub struct Profile<E> {
+    pub info: Option<UserInfo>,
+    pub summaries: Option<AllSummaries>,
+    /* The most recent records first. */
+    pub league_records: Option<Vec<LeagueRecord>>,
+    pub leagueflow: Option<LeagueFlow>,
+    pub failures: Vec<(ProfilePart, PartFailure<E>)>,
+}
+
+impl<E> Profile<E> {
+    pub(crate) fn new() -> Self {
+        Self {
+            info: None,
+            summaries: None,
+            league_records: None,
+            leagueflow: None,
+            failures: vec![],
+        }
+    }
+
+    /// Keeps the data of a response, or the reason it has none.
+    pub(crate) fn take<T>(&mut self, part: ProfilePart, response: Result<Packet<T>, E>) -> Option<T> {
+        let failure = match response {
+            Ok(Packet { data: Some(data), success: true, .. }) => return Some(data),
+            Ok(packet) => PartFailure::Unsuccessful(packet.error.map(|error| error.msg)),
+            Err(error) => PartFailure::Request(error),
+        };
+
+        self.failures.push((part, failure));
+        None
+    }
+
+    /// Whether every requested part was fetched.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    pub fn failure(&self, part: ProfilePart) -> Option<&PartFailure<E>> {
+        self.failures.iter().find(|(failed, _)| *failed == part).map(|(_, failure)| failure)
+    }
+}
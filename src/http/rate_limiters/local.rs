@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use async_lock::Mutex;
+use async_trait::async_trait;
+use tokio::time::Instant;
+
+use super::rate_limiter::RateLimiter;
+
+/// Lets one request start every `interval`, for the current process only.
+/// Share it between clients by wrapping it in an `Arc`.
+pub struct LocalRateLimiter {
+    interval: Duration,
+    next: Mutex<Option<Instant>>,
+}
+
+impl LocalRateLimiter {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next: Mutex::new(None),
+        }
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+}
+
+impl Default for LocalRateLimiter {
+    /// 1 request every second.
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1))
+    }
+}
+
+#[async_trait]
+impl RateLimiter for LocalRateLimiter {
+    async fn acquire(&self) {
+        // The lock is held while waiting so requests start in the order they arrived.
+        let mut next = self.next.lock().await;
+
+        if let Some(next) = *next {
+            tokio::time::sleep_until(next).await;
+        }
+
+        *next = Some(Instant::now() + self.interval);
+    }
+}
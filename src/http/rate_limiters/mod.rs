@@ -0,0 +1,10 @@
+/// Trait used to define your own rate limiter
+pub mod rate_limiter;
+/// A rate limiter local to the process. This is the default.
+pub mod local;
+/// Request priorities, deciding which waiting request goes through the rate limiter next.
+pub mod priority;
+
+/// A redis token bucket, shared by every process using the same redis instance.
+#[cfg(feature = "redis_cache")]
+pub mod redis_rate_limiter;
@@ -0,0 +1,165 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use futures::channel::oneshot;
+use tokio::time::Instant;
+
+use super::rate_limiter::RateLimiter;
+
+/// How urgent a request is. Requests with a higher priority go through the rate limiter first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Priority {
+    /// Work nobody is actively waiting on, like leaderboard crawls.
+    Background,
+    #[default]
+    Normal,
+    /// Requests a user is waiting on, like bot commands.
+    Interactive,
+}
+
+impl Priority {
+    /// 0 for the most urgent requests.
+    fn level(&self) -> u32 {
+        match self {
+            Self::Interactive => 0,
+            Self::Normal => 1,
+            Self::Background => 2,
+        }
+    }
+}
+
+struct Waiter {
+    priority: Priority,
+    since: Instant,
+    sequence: u64,
+    turn: oneshot::Sender<()>,
+}
+
+#[derive(Default)]
+struct QueueState {
+    busy: bool,
+    sequence: u64,
+    waiters: Vec<Waiter>,
+}
+
+/// Orders the requests waiting on a rate limiter by priority.
+///
+/// Only one request waits on the rate limiter at a time; when it is let through, the waiting request with the highest priority goes next.
+/// To keep low priority requests moving, a request is promoted by one priority level every `aging` it spends waiting.
+/// Requests of the same effective priority are served in the order they arrived.
+pub struct PriorityQueue {
+    aging: Duration,
+    state: Mutex<QueueState>,
+}
+
+impl Default for PriorityQueue {
+    /// Promotes waiting requests every 30 seconds.
+    fn default() -> Self {
+        Self::new(Duration::from_secs(30))
+    }
+}
+
+impl PriorityQueue {
+    pub fn new(aging: Duration) -> Self {
+        Self {
+            aging,
+            state: Mutex::new(QueueState::default()),
+        }
+    }
+
+    /// The amount of requests waiting for their turn.
+    pub fn waiting(&self) -> usize {
+        self.lock().waiters.len()
+    }
+
+    /// Waits for this request's turn, then for the rate limiter.
+    pub async fn acquire(&self, priority: Priority, rate_limiter: &dyn RateLimiter) {
+        // The turn is passed on when the ticket is dropped, even if the request is cancelled.
+        let mut ticket = Ticket { queue: self, sequence: 0, turn: None };
+
+        {
+            let mut state = self.lock();
+
+            if state.busy {
+                let (sender, receiver) = oneshot::channel();
+                state.sequence += 1;
+                ticket.sequence = state.sequence;
+                ticket.turn = Some(receiver);
+                state.waiters.push(Waiter { priority, since: Instant::now(), sequence: ticket.sequence, turn: sender });
+            } else {
+                state.busy = true;
+            }
+        }
+
+        if let Some(turn) = ticket.turn.as_mut() {
+            // The sender can't be dropped without sending while this request is waiting.
+            let _ = turn.await;
+            ticket.turn = None;
+        }
+
+        rate_limiter.acquire().await;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, QueueState> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn effective_level(&self, waiter: &Waiter, now: Instant) -> u32 {
+        let promotions = match self.aging.as_millis() {
+            0 => u32::MAX,
+            aging => (now.duration_since(waiter.since).as_millis() / aging).min(u32::MAX as u128) as u32,
+        };
+
+        waiter.priority.level().saturating_sub(promotions)
+    }
+
+    fn pass_turn(&self, state: &mut QueueState) {
+        let now = Instant::now();
+
+        loop {
+            let next = state
+                .waiters
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, waiter)| (self.effective_level(waiter, now), waiter.sequence))
+                .map(|(index, _)| index);
+
+            match next {
+                Some(index) => {
+                    let waiter = state.waiters.swap_remove(index);
+                    // A waiter which was cancelled can't take its turn, the next one gets it instead.
+                    if waiter.turn.send(()).is_ok() {
+                        return;
+                    }
+                }
+                None => {
+                    state.busy = false;
+                    return;
+                }
+            }
+        }
+    }
+}
+
+struct Ticket<'a> {
+    queue: &'a PriorityQueue,
+    sequence: u64,
+    /* Some while waiting for the turn, None once the turn was taken. */
+    turn: Option<oneshot::Receiver<()>>,
+}
+
+impl Drop for Ticket<'_> {
+    fn drop(&mut self) {
+        let mut state = self.queue.lock();
+
+        match self.turn.as_mut().map(|turn| turn.try_recv()) {
+            // The request was cancelled while waiting, and its turn hasn't come yet.
+            Some(Ok(None)) => {
+                let sequence = self.sequence;
+                state.waiters.retain(|waiter| waiter.sequence != sequence);
+            }
+            // Either the request got its turn, or the turn was given right before it was cancelled.
+            _ => self.queue.pass_turn(&mut state),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+/// Decides when requests are allowed to start.
+/// The cached client waits on [`RateLimiter::acquire`] before every request sent to the API. Cache hits never go through it.
+#[async_trait]
+pub trait RateLimiter: 'static + Send + Sync {
+    /// Waits until a request is allowed to start.
+    /// Implementations are expected to handle their own errors (for example by falling back to a local limit), a request is never cancelled by the rate limiter.
+    async fn acquire(&self);
+}
+
+#[async_trait]
+impl<T: RateLimiter + ?Sized> RateLimiter for Arc<T> {
+    async fn acquire(&self) {
+        self.as_ref().acquire().await
+    }
+}
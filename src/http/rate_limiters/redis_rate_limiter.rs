@@ -0,0 +1,141 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use redis::aio::MultiplexedConnection;
+use tokio::sync::Mutex;
+
+use super::{local::LocalRateLimiter, rate_limiter::RateLimiter};
+
+/// Takes a token from the bucket if one is available.
+/// Returns 0 when a token was taken, or the amount of milliseconds to wait before a token is available.
+/// The time of the redis server is used so that every process agrees on it.
+const TOKEN_BUCKET_SCRIPT: &str = r#"
+local capacity = tonumber(ARGV[1])
+local interval = tonumber(ARGV[2])
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+
+local bucket = redis.call('HMGET', KEYS[1], 'tokens', 'ts')
+local tokens = tonumber(bucket[1]) or capacity
+local ts = tonumber(bucket[2]) or now
+tokens = math.min(capacity, tokens + math.max(0, now - ts) / interval)
+
+local wait = 0
+if tokens >= 1 then
+    tokens = tokens - 1
+else
+    wait = math.ceil((1 - tokens) * interval)
+end
+
+redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'ts', now)
+redis.call('PEXPIRE', KEYS[1], math.ceil(capacity * interval) + 1000)
+return wait
+"#;
+
+/// A token bucket stored in redis, shared by every process using the same redis instance and key.
+///
+/// The bucket holds up to `capacity` tokens and gets a new one every `interval`.
+/// A single multiplexed connection is opened on first use and shared by every request.
+/// When redis can't be reached or doesn't answer within the timeout, the limiter falls back to a [`LocalRateLimiter`] until redis answers again.
+/// As the fallback only knows about the current process, it should be configured for the share of the limit that process is allowed to use.
+pub struct RedisRateLimiter {
+    client: redis::Client,
+    connection: Mutex<Option<MultiplexedConnection>>,
+    script: redis::Script,
+    key: String,
+    capacity: u32,
+    interval: Duration,
+    timeout: Duration,
+    fallback: LocalRateLimiter,
+}
+
+impl RedisRateLimiter {
+    pub const DEFAULT_KEY: &'static str = "tetrio-api:rate-limit";
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(500);
+
+    /// A bucket holding a single token, refilled every second, which matches the default local rate limit.
+    pub fn new(client: redis::Client) -> Self {
+        Self::with_bucket(client, Self::DEFAULT_KEY, 1, Duration::from_secs(1))
+    }
+
+    pub fn with_bucket(client: redis::Client, key: impl Into<String>, capacity: u32, interval: Duration) -> Self {
+        Self {
+            client,
+            connection: Mutex::new(None),
+            script: redis::Script::new(TOKEN_BUCKET_SCRIPT),
+            key: key.into(),
+            capacity: capacity.max(1),
+            interval,
+            timeout: Self::DEFAULT_TIMEOUT,
+            fallback: LocalRateLimiter::new(interval),
+        }
+    }
+
+    /// Replaces the rate limiter used while redis can't be reached.
+    pub fn with_fallback(mut self, fallback: LocalRateLimiter) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Sets how long to wait for redis, connecting included, before using the fallback for a request.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Tries to take a token, returns how long to wait before trying again if none was available.
+    pub async fn try_acquire(&self) -> redis::RedisResult<Option<Duration>> {
+        match tokio::time::timeout(self.timeout, self.invoke()).await {
+            Ok(result) => result,
+            Err(elapsed) => {
+                // The connection may be stuck, a new one is opened on the next request.
+                *self.connection.lock().await = None;
+                Err(std::io::Error::new(std::io::ErrorKind::TimedOut, elapsed).into())
+            }
+        }
+    }
+
+    async fn invoke(&self) -> redis::RedisResult<Option<Duration>> {
+        let mut con = self.connection().await?;
+
+        let result = self.script
+            .key(&self.key)
+            .arg(self.capacity)
+            .arg(self.interval.as_millis().max(1) as u64)
+            .invoke_async::<u64>(&mut con)
+            .await;
+
+        if let Err(error) = &result {
+            if error.is_io_error() || error.is_connection_dropped() {
+                *self.connection.lock().await = None;
+            }
+        }
+
+        let wait = result?;
+        Ok((wait > 0).then(|| Duration::from_millis(wait)))
+    }
+
+    async fn connection(&self) -> redis::RedisResult<MultiplexedConnection> {
+        let mut connection = self.connection.lock().await;
+        if let Some(con) = connection.as_ref() {
+            return Ok(con.clone());
+        }
+
+        let con = self.client.get_multiplexed_async_connection().await?;
+        *connection = Some(con.clone());
+        Ok(con)
+    }
+}
+
+#[async_trait]
+impl RateLimiter for RedisRateLimiter {
+    async fn acquire(&self) {
+        loop {
+            match self.try_acquire().await {
+                Ok(None) => return,
+                Ok(Some(wait)) => tokio::time::sleep(wait).await,
+                Err(_) => return self.fallback.acquire().await,
+            }
+        }
+    }
+}
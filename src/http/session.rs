@@ -0,0 +1,119 @@
+use std::collections::hash_map::RandomState;
+use std::fmt::Display;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::cached_client::CachedClient;
+use super::caches::cache::CacheHandler;
+use super::clients::http_client::HttpClient;
+use super::error::ErrorTrait;
+use super::parameters::leaderboard_query::{HistoricalLeaderboardType, LeaderboardType};
+use super::parameters::personal_user_records::{PersonalLeaderboard, PersonalRecordsQuery};
+use super::parameters::value_bound_query::ValueBoundQuery;
+use crate::models::packet::Packet;
+use crate::models::season::Season;
+use crate::models::users::user_history_leaderboard::HistoricalLeaderboardPacket;
+use crate::models::users::user_leaderboard::LeaderboardPacket;
+use crate::models::users::user_records::{PersonalBlitzRecordPacket, PersonalLeagueRecordPacket, PersonalSprintRecordPacket, PersonalZenithExRecordPacket, PersonalZenithRecordPacket, RecordsLeaderboardPacket};
+
+static SESSION_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Generates a new random session ID.
+pub fn generate_session_id() -> String {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(SESSION_COUNTER.fetch_add(1, Ordering::Relaxed));
+    hasher.write_u128(SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_nanos()));
+
+    format!("tetrio-api-{:016x}", hasher.finish())
+}
+
+/// A handle sending the same X-SESSION-ID with every request made through it.
+///
+/// The API uses the session ID to keep serving the same snapshot of a leaderboard while it is being paged through,
+/// so every page fetched through a session is consistent with the others.
+/// Responses are cached per session, they never leak into requests made without this session ID.
+pub struct Session<'a, HttpClientImpl: HttpClient, Cache: CacheHandler<HttpClientImpl::HttpError>> {
+    client: &'a CachedClient<HttpClientImpl, Cache>,
+    id: String,
+}
+
+impl<'a, HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImpl::HttpError>> Session<'a, HttpClientImpl, Cache> {
+    pub fn new(client: &'a CachedClient<HttpClientImpl, Cache>) -> Self {
+        Self::with_id(client, generate_session_id())
+    }
+
+    pub fn with_id(client: &'a CachedClient<HttpClientImpl, Cache>, id: impl Into<String>) -> Self {
+        Self { client, id: id.into() }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn client(&self) -> &'a CachedClient<HttpClientImpl, Cache> {
+        self.client
+    }
+
+    /// Makes any API request using this session, see [`CachedClient::make_tetrio_api_request`].
+    pub async fn make_tetrio_api_request<T: DeserializeOwned + Serialize + Send + Sync + Clone>(&self, route: impl Display) -> Result<Packet<T>, <CachedClient<HttpClientImpl, Cache> as ErrorTrait>::Error> {
+        self.client.make_tetrio_api_request(route, Some(&self.id)).await
+    }
+
+    pub async fn fetch_leaderboard(&self,
+                                   leaderboard_type: LeaderboardType,
+                                   query: ValueBoundQuery) -> Result<LeaderboardPacket, <CachedClient<HttpClientImpl, Cache> as ErrorTrait>::Error> {
+        self.client.fetch_leaderboard(leaderboard_type, query, Some(&self.id)).await
+    }
+
+    pub async fn fetch_historical_leaderboard(&self,
+                                   leaderboard_type: HistoricalLeaderboardType,
+                                   season: &Season,
+                                   query: ValueBoundQuery) -> Result<HistoricalLeaderboardPacket, <CachedClient<HttpClientImpl, Cache> as ErrorTrait>::Error> {
+        self.client.fetch_historical_leaderboard(leaderboard_type, season, query, Some(&self.id)).await
+    }
+
+    pub async fn fetch_records_leaderboard<T: DeserializeOwned + Serialize + Send + Sync + Clone>(&self,
+                                   leaderboard: &str,
+                                   query: ValueBoundQuery) -> Result<RecordsLeaderboardPacket<T>, <CachedClient<HttpClientImpl, Cache> as ErrorTrait>::Error> {
+        self.client.fetch_records_leaderboard(leaderboard, query, Some(&self.id)).await
+    }
+
+    pub async fn fetch_user_personal_40l_records(&self,
+                                             user: &str,
+                                             leaderboard: PersonalLeaderboard,
+                                             query: PersonalRecordsQuery) -> Result<PersonalSprintRecordPacket, <CachedClient<HttpClientImpl, Cache> as ErrorTrait>::Error> {
+        self.client.fetch_user_personal_records(user, "40l", leaderboard, query, Some(&self.id)).await
+    }
+
+    pub async fn fetch_user_personal_blitz_records(&self,
+                                             user: &str,
+                                             leaderboard: PersonalLeaderboard,
+                                             query: PersonalRecordsQuery) -> Result<PersonalBlitzRecordPacket, <CachedClient<HttpClientImpl, Cache> as ErrorTrait>::Error> {
+        self.client.fetch_user_personal_records(user, "blitz", leaderboard, query, Some(&self.id)).await
+    }
+
+    pub async fn fetch_user_personal_league_records(&self,
+                                             user: &str,
+                                             leaderboard: PersonalLeaderboard,
+                                             query: PersonalRecordsQuery) -> Result<PersonalLeagueRecordPacket, <CachedClient<HttpClientImpl, Cache> as ErrorTrait>::Error> {
+        self.client.fetch_user_personal_records(user, "league", leaderboard, query, Some(&self.id)).await
+    }
+
+    pub async fn fetch_user_personal_zenith_records(&self,
+                                             user: &str,
+                                             leaderboard: PersonalLeaderboard,
+                                             query: PersonalRecordsQuery) -> Result<PersonalZenithRecordPacket, <CachedClient<HttpClientImpl, Cache> as ErrorTrait>::Error> {
+        self.client.fetch_user_personal_records(user, "zenith", leaderboard, query, Some(&self.id)).await
+    }
+
+    pub async fn fetch_user_personal_zenithex_records(&self,
+                                             user: &str,
+                                             leaderboard: PersonalLeaderboard,
+                                             query: PersonalRecordsQuery) -> Result<PersonalZenithExRecordPacket, <CachedClient<HttpClientImpl, Cache> as ErrorTrait>::Error> {
+        self.client.fetch_user_personal_records(user, "zenithex", leaderboard, query, Some(&self.id)).await
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::{HashSet, VecDeque};
+use std::time::{Duration, SystemTime};
+
+use futures::Stream;
+use tokio::time::Instant;
+
+use super::cached_client::CachedClient;
+use super::caches::cache::CacheHandler;
+use super::clients::http_client::HttpClient;
+use super::error::ErrorTrait;
+use super::parameters::personal_user_records::GameMode;
+use super::profile::PartFailure;
+use crate::models::{
+    common::{APIfloat, APIint, APIstring},
+    packet::Packet,
+    users::{summaries::AllSummaries, user_badge::UserBadge, user_info::UserInfo, user_rank::UserRank, user_role::UserRole},
+};
+
+/// How often a user is polled and which changes are worth an event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WatchOptions {
+    /* The minimum time between two polls, polls also wait for the cached data to expire. */
+    pub interval: Duration,
+    /* The smallest TR change which emits an event. */
+    pub tr_threshold: APIfloat,
+}
+
+impl WatchOptions {
+    /// Emits an event for TR changes of 10 or more.
+    pub fn new(interval: Duration) -> Self {
+        Self { interval, tr_threshold: 10.0 }
+    }
+
+    pub fn with_tr_threshold(mut self, tr_threshold: APIfloat) -> Self {
+        self.tr_threshold = tr_threshold;
+        self
+    }
+}
+
+/// A change noticed between two polls of a user.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserEvent {
+    PersonalBest {
+        mode: GameMode,
+        record_id: APIstring,
+        replay_id: APIstring,
+        /* None if the user had no record in this mode before. */
+        previous_record_id: Option<APIstring>,
+    },
+    RankUp { previous: UserRank, current: UserRank },
+    RankDown { previous: UserRank, current: UserRank },
+    TrChanged { previous: APIfloat, current: APIfloat },
+    /// The user played TETRA LEAGUE matches since the last poll.
+    LeagueMatches { played: APIint, won: APIint },
+    UsernameChanged { previous: APIstring, current: APIstring },
+    CountryChanged { previous: Option<APIstring>, current: Option<APIstring> },
+    BadgesGained { badges: Vec<UserBadge> },
+    RoleChanged { previous: UserRole, current: UserRole },
+}
+
+/// What is known about a user at one poll.
+#[derive(Debug, Clone)]
+pub struct UserSnapshot {
+    pub info: UserInfo,
+    pub summaries: AllSummaries,
+}
+
+impl UserSnapshot {
+    /// The TR of the user, None if they don't have one.
+    pub fn tr(&self) -> Option<APIfloat> {
+        // The API uses -1 for players without a TR.
+        self.summaries.league.tr.filter(|tr| *tr >= 0.0)
+    }
+
+    /// The events which happened between this snapshot and the next one, in a stable order.
+    pub fn diff(&self, next: &UserSnapshot, tr_threshold: APIfloat) -> Vec<UserEvent> {
+        self.diff_since(next, tr_threshold, self.tr())
+    }
+
+    /// Like [`UserSnapshot::diff`], but TR changes are measured from `reported_tr`, the TR of the last reported change,
+    /// so that TR drifting by less than the threshold at every poll is still reported once it adds up.
+    pub fn diff_since(&self, next: &UserSnapshot, tr_threshold: APIfloat, reported_tr: Option<APIfloat>) -> Vec<UserEvent> {
+        let mut events = vec![];
+
+        let (previous, current) = (&self.info, &next.info);
+        if previous.username != current.username {
+            events.push(UserEvent::UsernameChanged { previous: previous.username.clone(), current: current.username.clone() });
+        }
+        if previous.country != current.country {
+            events.push(UserEvent::CountryChanged { previous: previous.country.clone(), current: current.country.clone() });
+        }
+        if previous.role != current.role {
+            events.push(UserEvent::RoleChanged { previous: previous.role.clone(), current: current.role.clone() });
+        }
+
+        let known = previous.badges.iter().map(|badge| &badge.id).collect::<HashSet<_>>();
+        let gained = current.badges.iter().filter(|badge| !known.contains(&badge.id)).cloned().collect::<Vec<_>>();
+        if !gained.is_empty() {
+            events.push(UserEvent::BadgesGained { badges: gained });
+        }
+
+        let (previous, current) = (&self.summaries, &next.summaries);
+        let records = [
+            (GameMode::Sprint, previous.sprint.record.as_ref().map(|record| (&record.id, &record.replayid)), current.sprint.record.as_ref().map(|record| (&record.id, &record.replayid))),
+            (GameMode::Blitz, previous.blitz.record.as_ref().map(|record| (&record.id, &record.replayid)), current.blitz.record.as_ref().map(|record| (&record.id, &record.replayid))),
+            (GameMode::Zenith, previous.zenith.best.record.as_ref().map(|record| (&record.id, &record.replayid)), current.zenith.best.record.as_ref().map(|record| (&record.id, &record.replayid))),
+            (GameMode::ZenithEX, previous.zenithex.best.record.as_ref().map(|record| (&record.id, &record.replayid)), current.zenithex.best.record.as_ref().map(|record| (&record.id, &record.replayid))),
+        ];
+        for (mode, previous, current) in records {
+            if let Some((record_id, replay_id)) = current.filter(|(id, _)| previous.map(|(previous, _)| previous) != Some(*id)) {
+                events.push(UserEvent::PersonalBest {
+                    mode,
+                    record_id: record_id.clone(),
+                    replay_id: replay_id.clone(),
+                    previous_record_id: previous.map(|(id, _)| id.clone()),
+                });
+            }
+        }
+
+        let (previous, current) = (&previous.league, &current.league);
+        if let (Some(previous), Some(current)) = (&previous.rank, &current.rank) {
+            if current > previous {
+                events.push(UserEvent::RankUp { previous: previous.clone(), current: current.clone() });
+            } else if current < previous {
+                events.push(UserEvent::RankDown { previous: previous.clone(), current: current.clone() });
+            }
+        }
+
+        if let (Some(previous), Some(current)) = (reported_tr, next.tr()) {
+            if (current - previous).abs() >= tr_threshold {
+                events.push(UserEvent::TrChanged { previous, current });
+            }
+        }
+
+        let played = current.gamesplayed.unwrap_or(0) - previous.gamesplayed.unwrap_or(0);
+        if played > 0 {
+            let won = (current.gameswon.unwrap_or(0) - previous.gameswon.unwrap_or(0)).clamp(0, played);
+            events.push(UserEvent::LeagueMatches { played, won });
+        }
+
+        events
+    }
+}
+
+type ClientError<HttpClientImpl, Cache> = <CachedClient<HttpClientImpl, Cache> as ErrorTrait>::Error;
+type WatchFailure<HttpClientImpl, Cache> = PartFailure<ClientError<HttpClientImpl, Cache>>;
+type WatchItem<HttpClientImpl, Cache> = Result<UserEvent, WatchFailure<HttpClientImpl, Cache>>;
+
+struct WatchState<'a, HttpClientImpl: HttpClient, Cache: CacheHandler<HttpClientImpl::HttpError>> {
+    client: &'a CachedClient<HttpClientImpl, Cache>,
+    user: String,
+    options: WatchOptions,
+    previous: Option<UserSnapshot>,
+    reported_tr: Option<APIfloat>,
+    pending: VecDeque<WatchItem<HttpClientImpl, Cache>>,
+    next_poll: Option<Instant>,
+}
+
+impl<HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImpl::HttpError>> WatchState<'_, HttpClientImpl, Cache> {
+    async fn poll(&mut self) {
+        // Boxed, as the responses would make the stream's future too large for small stacks.
+        let (info, summaries) = futures::join!(Box::pin(self.client.fetch_user_info(&self.user)), Box::pin(self.client.fetch_user_summaries(&self.user)));
+        let mut next_poll = Instant::now() + self.options.interval;
+
+        match (Self::take(info), Self::take(summaries)) {
+            (Ok((info, info_cache)), Ok((summaries, summaries_cache))) => {
+                // Data can't change before the first of the responses expires.
+                // The API sends wall clock dates, the time left is waited for on the tokio clock like the interval.
+                let expiry = info_cache.into_iter().chain(summaries_cache).map(|cache| cache.cached_until_time()).min();
+                if let Some(wait) = expiry.and_then(|expiry| expiry.duration_since(SystemTime::now()).ok()) {
+                    next_poll = next_poll.max(Instant::now() + wait);
+                }
+
+                let snapshot = UserSnapshot { info, summaries };
+                if let Some(previous) = &self.previous {
+                    let events = previous.diff_since(&snapshot, self.options.tr_threshold, self.reported_tr);
+                    if self.reported_tr.is_none() || events.iter().any(|event| matches!(event, UserEvent::TrChanged { .. })) {
+                        self.reported_tr = snapshot.tr();
+                    }
+                    self.pending.extend(events.into_iter().map(Ok));
+                } else {
+                    self.reported_tr = snapshot.tr();
+                }
+                self.previous = Some(snapshot);
+            }
+            (info, summaries) => {
+                self.pending.extend(info.err().into_iter().chain(summaries.err()).map(Err));
+            }
+        }
+
+        self.next_poll = Some(next_poll);
+    }
+
+    fn take<T>(response: Result<Packet<T>, ClientError<HttpClientImpl, Cache>>) -> Result<(T, Option<crate::models::cache::Cache>), WatchFailure<HttpClientImpl, Cache>> {
+        match response {
+            Ok(Packet { success: true, data: Some(data), cache, .. }) => Ok((data, cache)),
+            Ok(packet) => Err(PartFailure::Unsuccessful(packet.error.map(|error| error.msg))),
+            Err(error) => Err(PartFailure::Request(error)),
+        }
+    }
+}
+
+/// See [`CachedClient::watch_user`].
+pub(crate) fn watch_user<'a, HttpClientImpl: HttpClient + Send + Sync, Cache: CacheHandler<HttpClientImpl::HttpError>>(
+    client: &'a CachedClient<HttpClientImpl, Cache>,
+    user: &str,
+    options: WatchOptions,
+) -> impl Stream<Item = WatchItem<HttpClientImpl, Cache>> + 'a {
+    let state = WatchState {
+        client,
+        user: user.to_string(),
+        options,
+        previous: None,
+        reported_tr: None,
+        pending: VecDeque::new(),
+        next_poll: None,
+    };
+
+    futures::stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(item) = state.pending.pop_front() {
+                return Some((item, state));
+            }
+
+            if let Some(next_poll) = state.next_poll {
+                tokio::time::sleep_until(next_poll).await;
+            }
+            state.poll().await;
+        }
+    })
+}
@@ -11,4 +11,23 @@ pub mod http;
 /// There might be some innacuracies compared to the models in the [TETR.IO API Specs](https://tetr.io/about/api/)
 pub mod models;
 
+/// Rating formulas and derived statistics built on top of the models
+/// You will find in here the Glicko-2 / TR / GLIXARE conversions used by TETRA LEAGUE.
+pub mod math;
 
+/// Summaries derived from the records returned by the API
+/// You will find in here match recaps and run breakdowns which are not part of the API itself.
+pub mod analysis;
+
+/// Links to the images and pages of TETR.IO
+/// You will find in here avatar, banner, badge, flag and rank icon URLs, as well as profile, replay and record links.
+pub mod urls;
+
+/// Discord embeds rendered from the models, behind the `embeds` feature
+/// You will find in here user profiles, records, match recaps and leaderboard pages shaped like Discord embed JSON.
+#[cfg(feature = "embeds")]
+pub mod embeds;
+
+/// Flat exports of leaderboards and records
+/// You will find in here CSV and NDJSON writers, and a Parquet writer behind the `parquet_export` feature.
+pub mod export;
@@ -0,0 +1,284 @@
+//!
+//! Glicko-2 helpers matching the way TETRA LEAGUE derives TR and GLIXARE from a player's Glicko-2 rating.
+//! Rating changes are estimates: the API does not expose volatility, so [`DEFAULT_VOLATILITY`] is assumed unless specified.
+
+use std::f64::consts::{LN_10, PI};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    common::APIfloat,
+    users::{
+        summaries::tetra_league::LeagueSummary, user_leaderboard::LeaderboardUserLeague,
+        user_records::league_record::LeagueExtrasData,
+    },
+};
+
+pub use crate::models::labs::league_ranks::MAX_TR;
+
+/// The conversion factor between the Glicko-1 and Glicko-2 scales.
+pub const GLICKO2_SCALE: APIfloat = 173.7178;
+/// The rating of an average player.
+pub const DEFAULT_GLICKO: APIfloat = 1500.0;
+/// The highest rating deviation a player can have.
+pub const MAX_RD: APIfloat = 350.0;
+/// A player whose rating deviation is above this value is unranked.
+pub const UNRANKED_RD: APIfloat = 100.0;
+/// The volatility assumed when none is known.
+pub const DEFAULT_VOLATILITY: APIfloat = 0.06;
+/// The system constant constraining volatility changes.
+pub const DEFAULT_TAU: APIfloat = 0.5;
+
+const CONVERGENCE_TOLERANCE: APIfloat = 0.000001;
+
+fn g(phi: APIfloat) -> APIfloat {
+    1.0 / (1.0 + 3.0 * phi.powi(2) / PI.powi(2)).sqrt()
+}
+
+fn expected(mu: APIfloat, opponent_mu: APIfloat, opponent_phi: APIfloat) -> APIfloat {
+    1.0 / (1.0 + (-g(opponent_phi) * (mu - opponent_mu)).exp())
+}
+
+/// Converts a Glicko-2 rating and RD into a GLIXARE score, the % chance of beating an average player.
+pub fn gxe(glicko: APIfloat, rd: APIfloat) -> APIfloat {
+    let denominator = (3.0 * LN_10.powi(2) * rd.powi(2) + 2500.0 * (64.0 * PI.powi(2) + 147.0 * LN_10.powi(2))).sqrt();
+
+    100.0 / (1.0 + 10f64.powf((DEFAULT_GLICKO - glicko) * PI / denominator))
+}
+
+/// Converts a Glicko-2 rating and RD into a TR (Tetra Rating).
+pub fn tr(glicko: APIfloat, rd: APIfloat) -> APIfloat {
+    tr_from_gxe(gxe(glicko, rd))
+}
+
+/// Converts a GLIXARE score into a TR (Tetra Rating).
+pub fn tr_from_gxe(gxe: APIfloat) -> APIfloat {
+    gxe / 100.0 * MAX_TR
+}
+
+/// Finds the Glicko-2 rating giving the provided TR for a given RD.
+/// Returns None if the TR is outside of the ]0; 25000[ range.
+pub fn glicko_from_tr(tr: APIfloat, rd: APIfloat) -> Option<APIfloat> {
+    let probability = tr / MAX_TR;
+    if probability <= 0.0 || probability >= 1.0 {
+        return None;
+    }
+
+    let denominator = (3.0 * LN_10.powi(2) * rd.powi(2) + 2500.0 * (64.0 * PI.powi(2) + 147.0 * LN_10.powi(2))).sqrt();
+
+    Some(DEFAULT_GLICKO - (1.0 / probability - 1.0).log10() * denominator / PI)
+}
+
+/// The probability (0~1) of the first player winning against the second one.
+/// Both rating deviations are taken into account.
+pub fn win_probability(glicko: APIfloat, rd: APIfloat, opponent_glicko: APIfloat, opponent_rd: APIfloat) -> APIfloat {
+    let phi = (rd.powi(2) + opponent_rd.powi(2)).sqrt() / GLICKO2_SCALE;
+
+    expected((glicko - DEFAULT_GLICKO) / GLICKO2_SCALE, (opponent_glicko - DEFAULT_GLICKO) / GLICKO2_SCALE, phi)
+}
+
+/// A Glicko-2 rating as used by TETRA LEAGUE.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GlickoRating {
+    /* The Glicko-2 rating. */
+    pub glicko: APIfloat,
+    /* The Glicko-2 Rating Deviation. */
+    pub rd: APIfloat,
+    /* The Glicko-2 volatility. */
+    pub volatility: APIfloat,
+}
+
+/// The outcome of a simulated game, see [`GlickoRating::after_game`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RatingChange {
+    pub before: GlickoRating,
+    pub after: GlickoRating,
+    /* The TR before the game. */
+    pub tr_before: APIfloat,
+    /* The TR after the game. */
+    pub tr_after: APIfloat,
+}
+
+impl RatingChange {
+    /// The TR won (positive) or lost (negative).
+    pub fn tr_delta(&self) -> APIfloat {
+        self.tr_after - self.tr_before
+    }
+
+    /// The Glicko-2 rating won (positive) or lost (negative).
+    pub fn glicko_delta(&self) -> APIfloat {
+        self.after.glicko - self.before.glicko
+    }
+}
+
+impl Default for GlickoRating {
+    fn default() -> Self {
+        Self::new(DEFAULT_GLICKO, MAX_RD)
+    }
+}
+
+impl GlickoRating {
+    /// Creates a rating with the [`DEFAULT_VOLATILITY`].
+    pub fn new(glicko: APIfloat, rd: APIfloat) -> Self {
+        Self::with_volatility(glicko, rd, DEFAULT_VOLATILITY)
+    }
+
+    pub fn with_volatility(glicko: APIfloat, rd: APIfloat, volatility: APIfloat) -> Self {
+        Self { glicko, rd, volatility }
+    }
+
+    /// Reads the rating of a TETRA LEAGUE summary.
+    /// Returns None if the user has played less than 10 games (the API then sends -1 or nothing).
+    pub fn from_summary(summary: &LeagueSummary) -> Option<Self> {
+        match (summary.glicko, summary.rd) {
+            (Some(glicko), Some(rd)) if glicko >= 0.0 && rd >= 0.0 => Some(Self::new(glicko, rd)),
+            _ => None,
+        }
+    }
+
+    /// The GLIXARE score of this rating.
+    pub fn gxe(&self) -> APIfloat {
+        gxe(self.glicko, self.rd)
+    }
+
+    /// The TR of this rating.
+    pub fn tr(&self) -> APIfloat {
+        tr(self.glicko, self.rd)
+    }
+
+    /// Whether the rating deviation is low enough for the player to be ranked.
+    pub fn is_ranked(&self) -> bool {
+        self.rd <= UNRANKED_RD
+    }
+
+    /// The probability (0~1) of winning against the opponent.
+    pub fn win_probability(&self, opponent: &GlickoRating) -> APIfloat {
+        win_probability(self.glicko, self.rd, opponent.glicko, opponent.rd)
+    }
+
+    /// Applies a Glicko-2 rating period.
+    /// Each result is an opponent and the score obtained against them (1 for a win, 0 for a loss).
+    /// An empty period only increases the rating deviation.
+    pub fn update(&self, results: &[(GlickoRating, APIfloat)], tau: APIfloat) -> GlickoRating {
+        let mu = (self.glicko - DEFAULT_GLICKO) / GLICKO2_SCALE;
+        let phi = self.rd / GLICKO2_SCALE;
+
+        if results.is_empty() {
+            return Self::with_volatility(self.glicko, self.projected_rd(1), self.volatility);
+        }
+
+        let (variance_inverse, improvement) = results.iter().fold((0.0, 0.0), |(variance, improvement), (opponent, score)| {
+            let opponent_mu = (opponent.glicko - DEFAULT_GLICKO) / GLICKO2_SCALE;
+            let opponent_phi = opponent.rd / GLICKO2_SCALE;
+            let e = expected(mu, opponent_mu, opponent_phi);
+
+            (
+                variance + g(opponent_phi).powi(2) * e * (1.0 - e),
+                improvement + g(opponent_phi) * (score - e),
+            )
+        });
+
+        let v = 1.0 / variance_inverse;
+        let delta = v * improvement;
+        let volatility = self.next_volatility(phi, v, delta, tau);
+
+        let phi_star = (phi.powi(2) + volatility.powi(2)).sqrt();
+        let new_phi = 1.0 / (1.0 / phi_star.powi(2) + 1.0 / v).sqrt();
+        let new_mu = mu + new_phi.powi(2) * improvement;
+
+        Self::with_volatility(
+            new_mu * GLICKO2_SCALE + DEFAULT_GLICKO,
+            (new_phi * GLICKO2_SCALE).min(MAX_RD),
+            volatility,
+        )
+    }
+
+    fn next_volatility(&self, phi: APIfloat, v: APIfloat, delta: APIfloat, tau: APIfloat) -> APIfloat {
+        let a = self.volatility.powi(2).ln();
+        let f = |x: APIfloat| {
+            let ex = x.exp();
+            ex * (delta.powi(2) - phi.powi(2) - v - ex) / (2.0 * (phi.powi(2) + v + ex).powi(2)) - (x - a) / tau.powi(2)
+        };
+
+        let mut upper = a;
+        let mut lower = if delta.powi(2) > phi.powi(2) + v {
+            (delta.powi(2) - phi.powi(2) - v).ln()
+        } else {
+            let mut k = 1.0;
+            while f(a - k * tau) < 0.0 {
+                k += 1.0;
+            }
+            a - k * tau
+        };
+
+        let mut f_upper = f(upper);
+        let mut f_lower = f(lower);
+
+        while (lower - upper).abs() > CONVERGENCE_TOLERANCE {
+            let c = upper + (upper - lower) * f_upper / (f_lower - f_upper);
+            let f_c = f(c);
+
+            if f_c * f_lower <= 0.0 {
+                upper = lower;
+                f_upper = f_lower;
+            } else {
+                f_upper /= 2.0;
+            }
+
+            lower = c;
+            f_lower = f_c;
+        }
+
+        (upper / 2.0).exp()
+    }
+
+    /// Simulates the rating after a single win or loss against the opponent.
+    pub fn after_game(&self, opponent: &GlickoRating, won: bool) -> RatingChange {
+        let score = if won { 1.0 } else { 0.0 };
+        let after = self.update(&[(*opponent, score)], DEFAULT_TAU);
+
+        RatingChange {
+            before: *self,
+            after,
+            tr_before: self.tr(),
+            tr_after: after.tr(),
+        }
+    }
+
+    /// Projects the rating deviation after a number of rating periods without any game,
+    /// which is what happens to a player whose league data is `decaying`.
+    pub fn projected_rd(&self, periods: u32) -> APIfloat {
+        let phi = self.rd / GLICKO2_SCALE;
+
+        ((phi.powi(2) + periods as APIfloat * self.volatility.powi(2)).sqrt() * GLICKO2_SCALE).min(MAX_RD)
+    }
+
+    /// The amount of inactive rating periods before the player becomes unranked.
+    /// Returns Some(0) if the player is already unranked, or None if the volatility is too low to ever reach it.
+    pub fn periods_until_unranked(&self) -> Option<u32> {
+        if !self.is_ranked() {
+            return Some(0);
+        }
+
+        let sigma = self.volatility.powi(2);
+        if sigma <= 0.0 {
+            return None;
+        }
+
+        let needed = ((UNRANKED_RD / GLICKO2_SCALE).powi(2) - (self.rd / GLICKO2_SCALE).powi(2)) / sigma;
+
+        Some(needed.floor() as u32 + 1)
+    }
+}
+
+impl From<&LeagueExtrasData> for GlickoRating {
+    fn from(value: &LeagueExtrasData) -> Self {
+        Self::new(value.glicko, value.rd)
+    }
+}
+
+impl From<&LeaderboardUserLeague> for GlickoRating {
+    fn from(value: &LeaderboardUserLeague) -> Self {
+        Self::new(value.glicko, value.rd)
+    }
+}
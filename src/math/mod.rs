@@ -0,0 +1,4 @@
+/// Glicko-2, TR and GLIXARE conversions as well as rating change simulations.
+pub mod glicko;
+/// Community-standard statistics derived from APM, PPS and VS.
+pub mod stats;
@@ -0,0 +1,200 @@
+//!
+//! Derived versus statistics ("nerd stats") computed from APM, PPS and VS.
+//! The formulas are the ones commonly used by the TETR.IO community tools.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    common::APIfloat,
+    labs::league_ranks::LeagueRank,
+    users::{
+        summaries::tetra_league::{LeagueSummary, LeagueSummaryPast},
+        user_history_leaderboard,
+        user_leaderboard::LeaderboardUserLeague,
+        user_records::league_record::{LeagueLeaderboardStats, LeagueRoundStats},
+    },
+};
+
+use super::glicko;
+
+/// Statistics derived from a player's APM, PPS and VS.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DerivedStats {
+    /* Attack per minute. */
+    pub apm: APIfloat,
+    /* Pieces per second. */
+    pub pps: APIfloat,
+    /* Versus score. */
+    pub vs: APIfloat,
+    /* Attack per piece. */
+    pub app: APIfloat,
+    /* Downstack (garbage cleared) per second. */
+    pub ds_second: APIfloat,
+    /* Downstack (garbage cleared) per piece. */
+    pub ds_piece: APIfloat,
+    /* Attack and downstack per piece. */
+    pub app_ds_piece: APIfloat,
+    /* Versus score per attack per minute. */
+    pub vs_apm: APIfloat,
+    /* How efficiently garbage is both sent and cleared. */
+    pub garbage_efficiency: APIfloat,
+    /* How much "cheese" (messy garbage) a player tends to send, higher is cheesier. */
+    pub cheese_index: APIfloat,
+    /* A weighted sum of every other statistic. */
+    pub weighted_area: APIfloat,
+    /* The TR estimated from the statistics alone. */
+    pub estimated_tr: APIfloat,
+}
+
+impl DerivedStats {
+    /// Computes every derived statistic.
+    /// Returns None if one of the values is not a finite positive number, since most ratios are then meaningless.
+    pub fn compute(apm: APIfloat, pps: APIfloat, vs: APIfloat) -> Option<Self> {
+        if [apm, pps, vs].iter().any(|value| !value.is_finite() || *value <= 0.0) {
+            return None;
+        }
+
+        let app = apm / (pps * 60.0);
+        let ds_second = vs / 100.0 - apm / 60.0;
+        let ds_piece = ds_second / pps;
+        let app_ds_piece = app + ds_piece;
+        let vs_apm = vs / apm;
+        let garbage_efficiency = app * ds_second / pps * 2.0;
+        let cheese_index = ds_piece * 150.0 + (vs_apm - 2.0) * 50.0 + (0.6 - app) * 125.0;
+        let weighted_area = apm + pps * 45.0 + vs * 0.444 + app * 185.0 + ds_second * 175.0 + ds_piece * 450.0 + garbage_efficiency * 315.0;
+
+        let estimation_base = pps * (150.0 + (vs_apm - 1.66) * 35.0) + app * 290.0 + ds_piece * 700.0;
+        let estimated_glicko = 0.000013 * estimation_base.powi(3) - 0.0196 * estimation_base.powi(2) + 12.645 * estimation_base - 1005.4;
+        let estimated_tr = glicko::tr(estimated_glicko, 60.0);
+
+        Some(Self {
+            apm,
+            pps,
+            vs,
+            app,
+            ds_second,
+            ds_piece,
+            app_ds_piece,
+            vs_apm,
+            garbage_efficiency,
+            cheese_index,
+            weighted_area,
+            estimated_tr,
+        })
+    }
+
+    /// The weighted APP, which penalizes attack sent as cheese.
+    pub fn weighted_app(&self) -> APIfloat {
+        self.app - 5.0 * (self.cheese_index / -30.0 + 1.0).to_radians().tan()
+    }
+}
+
+/// A trait implemented by every model holding APM, PPS and VS values.
+pub trait VersusStats {
+    fn apm(&self) -> Option<APIfloat>;
+    fn pps(&self) -> Option<APIfloat>;
+    fn vs(&self) -> Option<APIfloat>;
+
+    /// Computes the derived statistics, or None if a value is missing or invalid.
+    fn derived_stats(&self) -> Option<DerivedStats> {
+        DerivedStats::compute(self.apm()?, self.pps()?, self.vs()?)
+    }
+}
+
+impl VersusStats for LeagueSummary {
+    fn apm(&self) -> Option<APIfloat> {
+        self.apm
+    }
+
+    fn pps(&self) -> Option<APIfloat> {
+        self.pps
+    }
+
+    fn vs(&self) -> Option<APIfloat> {
+        self.vs
+    }
+}
+
+impl VersusStats for LeagueSummaryPast {
+    fn apm(&self) -> Option<APIfloat> {
+        Some(self.apm)
+    }
+
+    fn pps(&self) -> Option<APIfloat> {
+        Some(self.pps)
+    }
+
+    fn vs(&self) -> Option<APIfloat> {
+        Some(self.vs)
+    }
+}
+
+impl VersusStats for LeaderboardUserLeague {
+    fn apm(&self) -> Option<APIfloat> {
+        self.apm
+    }
+
+    fn pps(&self) -> Option<APIfloat> {
+        self.pps
+    }
+
+    fn vs(&self) -> Option<APIfloat> {
+        self.vs
+    }
+}
+
+impl VersusStats for user_history_leaderboard::LeaderboardUser {
+    fn apm(&self) -> Option<APIfloat> {
+        Some(self.apm)
+    }
+
+    fn pps(&self) -> Option<APIfloat> {
+        Some(self.pps)
+    }
+
+    fn vs(&self) -> Option<APIfloat> {
+        Some(self.vs)
+    }
+}
+
+impl VersusStats for LeagueRoundStats {
+    fn apm(&self) -> Option<APIfloat> {
+        Some(self.apm)
+    }
+
+    fn pps(&self) -> Option<APIfloat> {
+        Some(self.pps)
+    }
+
+    fn vs(&self) -> Option<APIfloat> {
+        Some(self.vsscore)
+    }
+}
+
+impl VersusStats for LeagueLeaderboardStats {
+    fn apm(&self) -> Option<APIfloat> {
+        self.apm
+    }
+
+    fn pps(&self) -> Option<APIfloat> {
+        self.pps
+    }
+
+    fn vs(&self) -> Option<APIfloat> {
+        self.vsscore
+    }
+}
+
+impl VersusStats for LeagueRank {
+    fn apm(&self) -> Option<APIfloat> {
+        self.apm
+    }
+
+    fn pps(&self) -> Option<APIfloat> {
+        self.pps
+    }
+
+    fn vs(&self) -> Option<APIfloat> {
+        self.vs
+    }
+}
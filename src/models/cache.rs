@@ -29,3 +29,25 @@ impl Cache {
         Duration::from_millis((self.cached_until - SystemTime::now().duration_since(UNIX_EPOCH).expect("That can't be happening").as_millis()) as u64)
     }
 }
+
+impl Cache {
+    /// The time at which the data was cached.
+    pub fn cached_at_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.cached_at as u64)
+    }
+
+    /// The time at which the cached data expires.
+    pub fn cached_until_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.cached_until as u64)
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn cached_at_timestamp(&self) -> Option<super::timestamp::Timestamp> {
+        super::timestamp::Timestamp::from_millis(self.cached_at.try_into().ok()?)
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn cached_until_timestamp(&self) -> Option<super::timestamp::Timestamp> {
+        super::timestamp::Timestamp::from_millis(self.cached_until.try_into().ok()?)
+    }
+}
@@ -7,3 +7,7 @@ pub type APIfloat = f64;
 pub type APIsmallfloat = f32;
 pub type APIArray<T> = Vec<T>;
 pub type APIintarray = APIArray<i64>;
+
+/// A date sent by the API as an ISO 8601 string.
+/// Typed accessors are available on [`Timestamp`](crate::models::timestamp::Timestamp) with the `chrono` feature.
+pub type APItimestamp = crate::models::timestamp::Timestamp;
@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    common::{APIint, APIstring},
+    general::achivement_info::{AchievementCutoffs, AchievementInfo},
+    users::{summaries::achievements::Achievement, user_info::UserInfo},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AchievementCatalogEntry {
+    pub achievement: Achievement,
+    /* The cutoffs at the time the achievement was fetched. */
+    pub cutoffs: AchievementCutoffs,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(from = "CatalogSnapshot")]
+/// The definitions of every known achievement, indexed by their ID (`k`).
+/// It can be built and refreshed through `CachedClient::refresh_achievement_catalog`,
+/// and serialized to be shipped as a snapshot.
+pub struct AchievementCatalog {
+    achievements: BTreeMap<APIint, AchievementCatalogEntry>,
+    /* The IDs of each category's achievements, in the order shown in game. Rebuilt when a snapshot is read. */
+    #[serde(skip)]
+    categories: BTreeMap<APIstring, Vec<APIint>>,
+}
+
+#[derive(Deserialize)]
+struct CatalogSnapshot {
+    achievements: BTreeMap<APIint, AchievementCatalogEntry>,
+}
+
+impl From<CatalogSnapshot> for AchievementCatalog {
+    fn from(snapshot: CatalogSnapshot) -> Self {
+        let mut catalog = AchievementCatalog::new();
+        for entry in snapshot.achievements.into_values() {
+            catalog.insert_entry(entry);
+        }
+        catalog
+    }
+}
+
+impl AchievementCatalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds (or replaces) the definition of an achievement.
+    pub fn insert(&mut self, info: AchievementInfo) {
+        self.insert_entry(AchievementCatalogEntry {
+            achievement: info.achievement,
+            cutoffs: info.cutoffs,
+        });
+    }
+
+    fn insert_entry(&mut self, entry: AchievementCatalogEntry) {
+        let id = entry.achievement.k;
+        self.remove(id);
+
+        let key = |id: &APIint| {
+            let achievement = &self.achievements[id].achievement;
+            (achievement.o.unwrap_or(APIint::MAX), achievement.k)
+        };
+        let order = (entry.achievement.o.unwrap_or(APIint::MAX), id);
+        let ids = self.categories.get(&entry.achievement.category).map(Vec::as_slice).unwrap_or_default();
+        let position = ids.partition_point(|other| key(other) < order);
+
+        self.categories.entry(entry.achievement.category.clone()).or_default().insert(position, id);
+        self.achievements.insert(id, entry);
+    }
+
+    pub fn remove(&mut self, id: APIint) -> Option<AchievementCatalogEntry> {
+        let entry = self.achievements.remove(&id)?;
+
+        if let Some(ids) = self.categories.get_mut(&entry.achievement.category) {
+            ids.retain(|other| *other != id);
+            if ids.is_empty() {
+                self.categories.remove(&entry.achievement.category);
+            }
+        }
+
+        Some(entry)
+    }
+
+    pub fn len(&self) -> usize {
+        self.achievements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.achievements.is_empty()
+    }
+
+    pub fn contains(&self, id: APIint) -> bool {
+        self.achievements.contains_key(&id)
+    }
+
+    pub fn get(&self, id: APIint) -> Option<&Achievement> {
+        self.achievements.get(&id).map(|entry| &entry.achievement)
+    }
+
+    pub fn entry(&self, id: APIint) -> Option<&AchievementCatalogEntry> {
+        self.achievements.get(&id)
+    }
+
+    pub fn cutoffs(&self, id: APIint) -> Option<&AchievementCutoffs> {
+        self.achievements.get(&id).map(|entry| &entry.cutoffs)
+    }
+
+    /// Every known achievement ID, in ascending order.
+    pub fn ids(&self) -> impl Iterator<Item = APIint> + '_ {
+        self.achievements.keys().copied()
+    }
+
+    /// Every known achievement, by ascending ID.
+    pub fn iter(&self) -> impl Iterator<Item = &Achievement> {
+        self.achievements.values().map(|entry| &entry.achievement)
+    }
+
+    /// The IDs which are not part of the catalog yet.
+    pub fn missing_ids(&self, ids: impl IntoIterator<Item = APIint>) -> Vec<APIint> {
+        let mut missing = ids.into_iter().filter(|id| !self.contains(*id)).collect::<Vec<_>>();
+        missing.sort_unstable();
+        missing.dedup();
+        missing
+    }
+
+    /// Every category, in alphabetical order.
+    pub fn categories(&self) -> Vec<&str> {
+        self.categories.keys().map(String::as_str).collect()
+    }
+
+    /// The achievements of a category, in the order they are shown in game (`o`).
+    pub fn category(&self, category: &str) -> Vec<&Achievement> {
+        self.categories.get(category).map_or_else(Vec::new, |ids| self.achievements_of(ids))
+    }
+
+    /// Every achievement grouped by category, each category being in the order shown in game (`o`).
+    pub fn by_category(&self) -> BTreeMap<&str, Vec<&Achievement>> {
+        self.categories.iter().map(|(category, ids)| (category.as_str(), self.achievements_of(ids))).collect()
+    }
+
+    fn achievements_of(&self, ids: &[APIint]) -> Vec<&Achievement> {
+        ids.iter().map(|id| &self.achievements[id].achievement).collect()
+    }
+
+    /// The achievements which are usually shown.
+    pub fn visible(&self) -> impl Iterator<Item = &Achievement> {
+        self.iter().filter(|achievement| !achievement.hidden)
+    }
+
+    /// The achievements tied to an event, along with the event's name.
+    pub fn events(&self) -> impl Iterator<Item = (&APIstring, &Achievement)> {
+        self.iter().filter_map(|achievement| achievement.event.as_ref().map(|event| (event, achievement)))
+    }
+
+    /// Resolves achievement IDs into their definitions, None for IDs which are not part of the catalog.
+    pub fn resolve<'a>(&'a self, ids: &'a [APIint]) -> impl Iterator<Item = (APIint, Option<&'a Achievement>)> + 'a {
+        ids.iter().map(|id| (*id, self.get(*id)))
+    }
+
+    /// The definitions of the achievements featured on a user's profile.
+    /// Achievements which are not part of the catalog are skipped.
+    pub fn featured<'a>(&'a self, user: &'a UserInfo) -> Vec<&'a Achievement> {
+        self.resolve(&user.achievements).filter_map(|(_, achievement)| achievement).collect()
+    }
+}
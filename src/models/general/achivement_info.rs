@@ -1,10 +1,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::models::{
-    common::{APIArray, APIfloat, APIint, APIstring}, packet::Packet, users::summaries::achievements::Achievement
+    common::{APIArray, APIfloat, APIint, APIstring, APItimestamp}, packet::Packet, users::summaries::achievements::{Achievement, AchievementRank}
 };
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AchievementLeaderboardUser {
     /* The user's internal ID. */
     pub _id: APIstring,
@@ -18,7 +18,7 @@ pub struct AchievementLeaderboardUser {
     pub country: Option<APIstring>,
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AchievementLeaderboard {
     /* The user owning the achievement: */
     pub u: AchievementLeaderboardUser,
@@ -27,10 +27,10 @@ pub struct AchievementLeaderboard {
     /* Additional score for the achievement. */
     pub a: Option<APIfloat>,
     /* The time the achievement was last updated. */
-    pub t: APIstring,
+    pub t: APItimestamp,
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AchievementCutoffs {
     /* The total amount of users with this achievement. */
     pub total: APIint,
@@ -46,7 +46,21 @@ pub struct AchievementCutoffs {
     pub bronze: Option<APIfloat>,
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+impl AchievementCutoffs {
+    /// The score required to obtain a medal, None if the medal cannot be obtained through a cutoff.
+    pub fn get(&self, rank: AchievementRank) -> Option<APIfloat> {
+        match rank {
+            AchievementRank::Bronze => self.bronze,
+            AchievementRank::Silver => self.silver,
+            AchievementRank::Gold => self.gold,
+            AchievementRank::Platinum => self.platinum,
+            AchievementRank::Diamond => self.diamond,
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AchievementInfo {
     pub achievement: Achievement,
     pub leaderboard: APIArray<AchievementLeaderboard>,
@@ -1,3 +1,4 @@
 pub mod activity;
 pub mod stats;
-pub mod achivement_info;
\ No newline at end of file
+pub mod achivement_info;
+pub mod achievement_catalog;
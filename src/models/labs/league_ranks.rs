@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 
 use crate::models::{
-    common::{APIfloat, APIint, APIstring},
+    common::{APIfloat, APIint, APIstring, APItimestamp},
     packet::Packet,
     users::user_rank::UserRank,
 };
@@ -44,7 +44,7 @@ pub struct LeagueRanks {
     /* The stream ID the Labs data point belongs to. */
     pub s: APIstring,
     /* The time at which the data point was created. */
-    pub t: APIstring,
+    pub t: APItimestamp,
     /* The data point: */
     pub data: LeagueRanksData,
     
@@ -53,3 +53,150 @@ pub struct LeagueRanks {
 }
 
 pub type LeagueRanksPacket = Packet<LeagueRanks>;
+
+/// The highest TR a player can reach in TETRA LEAGUE.
+pub const MAX_TR: APIfloat = 25000.0;
+
+/// A single row of the cutoff table returned by [`LeagueRanksData::cutoffs`].
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RankCutoff {
+    /* The rank this cutoff is for. */
+    pub rank: UserRank,
+    /* The TR required to obtain this rank. */
+    pub tr: APIfloat,
+    /* The TR this rank will gravitate toward. */
+    pub targettr: APIfloat,
+    /* The leaderboard position required to attain this rank. */
+    pub pos: APIint,
+    /* The percentile (0~1) this rank is for. */
+    pub percentile: APIfloat,
+    /* The amount of players with this rank. */
+    pub count: APIint,
+}
+
+impl RankCutoff {
+    /// Whether the rank is currently in a deflation or inflation zone, see [`RankZone`].
+    pub fn zone(&self) -> RankZone {
+        let difference = self.tr - self.targettr;
+
+        if difference.abs() < f64::EPSILON {
+            RankZone::Neutral
+        } else if difference > 0.0 {
+            RankZone::Deflation { excess: difference }
+        } else {
+            RankZone::Inflation { deficit: -difference }
+        }
+    }
+}
+
+/// How the TR cutoff of a rank is drifting compared to its `targettr`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum RankZone {
+    /// The cutoff sits above the target TR, ratings in this rank are being pulled down.
+    Deflation {
+        /// How much TR the cutoff is above the target.
+        excess: APIfloat,
+    },
+    /// The cutoff sits below the target TR, ratings in this rank are being pushed up.
+    Inflation {
+        /// How much TR the cutoff is below the target.
+        deficit: APIfloat,
+    },
+    /// The cutoff is exactly on target.
+    Neutral,
+}
+
+/// Where a given TR would stand on the current leaderboard, see [`LeagueRanksData::standing`].
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RankStanding {
+    /* The TR this standing was computed for. */
+    pub tr: APIfloat,
+    /* The rank a player with this TR would currently have. */
+    pub rank: UserRank,
+    /* The estimated percentile position (0 is best, 1 is worst). */
+    pub percentile: APIfloat,
+    /* The estimated position in the global leaderboard. */
+    pub position: APIint,
+    /* The rank above the current one, or None if already at the best rank. */
+    pub next_rank: Option<UserRank>,
+    /* The TR required to reach the next rank, or None if already at the best rank. */
+    pub tr_to_next: Option<APIfloat>,
+    /* The rank below the current one, or None if already at the worst rank. */
+    pub prev_rank: Option<UserRank>,
+    /* How much TR can be lost before dropping to the previous rank, or None if already at the worst rank. */
+    pub tr_to_prev: Option<APIfloat>,
+    /* The deflation / inflation zone of the current rank. */
+    pub zone: RankZone,
+}
+
+impl LeagueRanksData {
+    /// Returns the cutoff table ordered from the best rank to the worst one.
+    pub fn cutoffs(&self) -> Vec<RankCutoff> {
+        let mut cutoffs = self
+            .ranks
+            .iter()
+            .map(|(rank, data)| RankCutoff {
+                rank: rank.clone(),
+                tr: data.tr,
+                targettr: data.targettr,
+                pos: data.pos,
+                percentile: data.percentile,
+                count: data.count,
+            })
+            .collect::<Vec<_>>();
+
+        cutoffs.sort_by(|a, b| b.tr.total_cmp(&a.tr).then(a.pos.cmp(&b.pos)));
+
+        cutoffs
+    }
+
+    /// Returns the deflation / inflation zone of a rank, or None if the rank is not part of the data point.
+    pub fn zone(&self, rank: &UserRank) -> Option<RankZone> {
+        self.cutoffs().into_iter().find(|cutoff| &cutoff.rank == rank).map(|cutoff| cutoff.zone())
+    }
+
+    /// Estimates the rank, percentile and leaderboard position of a player with the given TR.
+    /// The position is linearly interpolated between the two surrounding cutoffs.
+    /// Returns None if the data point does not contain any rank.
+    pub fn standing(&self, tr: APIfloat) -> Option<RankStanding> {
+        let cutoffs = self.cutoffs();
+        let tr = tr.clamp(0.0, MAX_TR);
+
+        // Index of the best rank whose cutoff is reached, defaulting to the worst rank.
+        let index = cutoffs
+            .iter()
+            .position(|cutoff| tr >= cutoff.tr)
+            .unwrap_or(cutoffs.len().checked_sub(1)?);
+
+        let current = &cutoffs[index];
+        let above = index.checked_sub(1).map(|i| &cutoffs[i]);
+        let below = cutoffs.get(index + 1);
+
+        let ((upper_tr, upper_pos), (lower_tr, lower_pos)) = if tr >= current.tr {
+            (above.map_or((MAX_TR, 1), |cutoff| (cutoff.tr, cutoff.pos)), (current.tr, current.pos))
+        } else {
+            // Below the worst cutoff, interpolate down to the last position of the leaderboard.
+            ((current.tr, current.pos), (0.0, self.total))
+        };
+
+        let position = if upper_tr > lower_tr {
+            let progress = (tr - lower_tr) / (upper_tr - lower_tr);
+            lower_pos as f64 + progress * (upper_pos - lower_pos) as f64
+        } else {
+            lower_pos as f64
+        };
+        let position = (position.round() as APIint).clamp(1, self.total.max(1));
+
+        Some(RankStanding {
+            tr,
+            rank: current.rank.clone(),
+            percentile: position as f64 / self.total.max(1) as f64,
+            position,
+            next_rank: above.map(|cutoff| cutoff.rank.clone()),
+            tr_to_next: above.map(|cutoff| cutoff.tr - tr),
+            prev_rank: below.map(|cutoff| cutoff.rank.clone()),
+            tr_to_prev: below.map(|_| (tr - current.tr).max(0.0)),
+            zone: current.zone(),
+        })
+    }
+}
@@ -3,8 +3,84 @@ use serde::{Deserialize, Serialize};
 use crate::models::{common::APIint, packet::Packet};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
+/// A raw leagueflow point: the time offset from [`LeagueFlow::start_time`], the result of the match,
+/// the TR after the match and the opponent's TR (-1 if unranked).
 pub struct LeagueFlowPoint(pub APIint, pub APIint, pub APIint, pub APIint);
 
+impl LeagueFlowPoint {
+    /// The time offset from [`LeagueFlow::start_time`], in milliseconds.
+    pub fn offset(&self) -> APIint {
+        self.0
+    }
+
+    pub fn result(&self) -> LeagueFlowResult {
+        LeagueFlowResult::from(self.1)
+    }
+
+    /// The TR right after the match.
+    pub fn tr(&self) -> APIint {
+        self.2
+    }
+
+    /// The opponent's TR, None if the opponent was unranked.
+    pub fn opponent_tr(&self) -> Option<APIint> {
+        (self.3 >= 0).then_some(self.3)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(from = "APIint", into = "APIint")]
+pub enum LeagueFlowResult {
+    Victory,
+    Defeat,
+    VictoryByDisqualification,
+    DefeatByDisqualification,
+    Tie,
+    NoContest,
+    Nullified,
+    Unknown(APIint),
+}
+
+impl LeagueFlowResult {
+    pub fn is_victory(&self) -> bool {
+        matches!(self, Self::Victory | Self::VictoryByDisqualification)
+    }
+
+    pub fn is_defeat(&self) -> bool {
+        matches!(self, Self::Defeat | Self::DefeatByDisqualification)
+    }
+}
+
+impl From<APIint> for LeagueFlowResult {
+    fn from(value: APIint) -> Self {
+        match value {
+            1 => Self::Victory,
+            2 => Self::Defeat,
+            3 => Self::VictoryByDisqualification,
+            4 => Self::DefeatByDisqualification,
+            5 => Self::Tie,
+            6 => Self::NoContest,
+            7 => Self::Nullified,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<LeagueFlowResult> for APIint {
+    fn from(value: LeagueFlowResult) -> Self {
+        match value {
+            LeagueFlowResult::Victory => 1,
+            LeagueFlowResult::Defeat => 2,
+            LeagueFlowResult::VictoryByDisqualification => 3,
+            LeagueFlowResult::DefeatByDisqualification => 4,
+            LeagueFlowResult::Tie => 5,
+            LeagueFlowResult::NoContest => 6,
+            LeagueFlowResult::Nullified => 7,
+            LeagueFlowResult::Unknown(other) => other,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct LeagueFlow {
     #[serde(rename = "startTime")]
@@ -12,4 +88,38 @@ pub struct LeagueFlow {
     pub points: Vec<LeagueFlowPoint>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+/// A decoded leagueflow point.
+pub struct LeagueFlowEntry {
+    /* The time at which the match was played, in milliseconds since the UNIX epoch. */
+    pub ts: APIint,
+    pub result: LeagueFlowResult,
+    /* The TR right after the match. */
+    pub tr: APIint,
+    /* The opponent's TR, None if the opponent was unranked. */
+    pub opponent_tr: Option<APIint>,
+}
+
+impl LeagueFlowEntry {
+    #[cfg(feature = "chrono")]
+    pub fn timestamp(&self) -> Option<crate::models::timestamp::Timestamp> {
+        crate::models::timestamp::Timestamp::from_millis(self.ts)
+    }
+}
+
+impl LeagueFlow {
+    /// The points decoded with absolute timestamps.
+    pub fn entries(&self) -> Vec<LeagueFlowEntry> {
+        self.points
+            .iter()
+            .map(|point| LeagueFlowEntry {
+                ts: self.start_time + point.offset(),
+                result: point.result(),
+                tr: point.tr(),
+                opponent_tr: point.opponent_tr(),
+            })
+            .collect()
+    }
+}
+
 pub type LeagueFlowPacket = Packet<LeagueFlow>;
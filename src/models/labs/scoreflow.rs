@@ -3,8 +3,25 @@ use serde::{Deserialize, Serialize};
 use crate::models::{common::APIint, packet::Packet};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
+/// A raw scoreflow point: the time offset from [`ScoreFlow::start_time`], whether the run was a PB (0 or 1) and the score.
 pub struct ScoreFlowPoint(pub APIint, pub APIint, pub APIint);
 
+impl ScoreFlowPoint {
+    /// The time offset from [`ScoreFlow::start_time`], in milliseconds.
+    pub fn offset(&self) -> APIint {
+        self.0
+    }
+
+    pub fn is_pb(&self) -> bool {
+        self.1 == 1
+    }
+
+    /// The score of the run, the final time in milliseconds for 40 LINES.
+    pub fn score(&self) -> APIint {
+        self.2
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ScoreFlow {
     #[serde(rename = "startTime")]
@@ -12,4 +29,41 @@ pub struct ScoreFlow {
     pub points: Vec<ScoreFlowPoint>,
 }
 
-pub type ScoreFlowPacket = Packet<ScoreFlow>;
\ No newline at end of file
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+/// A decoded scoreflow point.
+pub struct ScoreFlowEntry {
+    /* The time at which the run was played, in milliseconds since the UNIX epoch. */
+    pub ts: APIint,
+    /* Whether the run was a personal best at the time. */
+    pub pb: bool,
+    /* The score of the run, the final time in milliseconds for 40 LINES. */
+    pub score: APIint,
+}
+
+impl ScoreFlowEntry {
+    #[cfg(feature = "chrono")]
+    pub fn timestamp(&self) -> Option<crate::models::timestamp::Timestamp> {
+        crate::models::timestamp::Timestamp::from_millis(self.ts)
+    }
+}
+
+impl ScoreFlow {
+    /// The points decoded with absolute timestamps.
+    pub fn entries(&self) -> Vec<ScoreFlowEntry> {
+        self.points
+            .iter()
+            .map(|point| ScoreFlowEntry {
+                ts: self.start_time + point.offset(),
+                pb: point.is_pb(),
+                score: point.score(),
+            })
+            .collect()
+    }
+
+    /// The runs which were personal bests at the time they were played.
+    pub fn personal_bests(&self) -> Vec<ScoreFlowEntry> {
+        self.entries().into_iter().filter(|entry| entry.pb).collect()
+    }
+}
+
+pub type ScoreFlowPacket = Packet<ScoreFlow>;
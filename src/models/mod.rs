@@ -19,4 +19,8 @@ pub mod general;
 
 pub mod common;
 
-pub mod labs;
\ No newline at end of file
+pub mod labs;
+
+pub mod season;
+/// Dates sent by the API, kept as ISO 8601 strings with typed accessors behind the `chrono` feature.
+pub mod timestamp;
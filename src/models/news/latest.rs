@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
-use crate::models::{common::APIstring, packet::Packet};
+use crate::models::{common::{APIstring, APItimestamp}, packet::Packet};
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct LatestNews {
     
     #[serde(rename = "_id")]
@@ -10,10 +10,10 @@ pub struct LatestNews {
     #[serde(rename = "type")]
     pub item_type: APIstring,
     pub data: serde_json::Value,
-    pub ts: APIstring
+    pub ts: APItimestamp
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct LatestNewsPacketData {
     pub news: Vec<LatestNews>
 }
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
-use crate::models::{common::APIstring, packet::Packet};
+use crate::models::{common::{APIstring, APItimestamp}, packet::Packet};
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct News {
     #[serde(rename = "_id")]
     pub id: APIstring,
@@ -9,10 +9,10 @@ pub struct News {
     #[serde(rename = "type")]
     pub item_type: APIstring,
     pub data: serde_json::Value,
-    pub ts: APIstring
+    pub ts: APItimestamp
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct NewsPacketData {
     pub news: Vec<News>
 }
@@ -0,0 +1,105 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::common::APIstring;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// What is known about a TETRA LEAGUE season.
+pub struct SeasonInfo {
+    pub id: &'static str,
+    pub name: &'static str,
+    /* The month the season ended in (YYYY-MM), None if it is still running. */
+    pub ended: Option<&'static str>,
+}
+
+/// Every season known to this crate, from the oldest to the newest.
+pub const KNOWN_SEASONS: [SeasonInfo; 2] = [
+    SeasonInfo { id: "1", name: "Season 1", ended: Some("2024-07") },
+    SeasonInfo { id: "2", name: "Season 2", ended: None },
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "APIstring", into = "APIstring")]
+/// A TETRA LEAGUE season ID, as used by the API (e.g. "1").
+/// Seasons are ordered chronologically; unknown non numeric IDs are ordered after numeric ones.
+/// Numeric IDs are normalized (e.g. "01" becomes "1") so that equal seasons have equal IDs.
+pub struct Season(APIstring);
+
+impl Season {
+    pub fn new(id: impl Into<APIstring>) -> Self {
+        let id = id.into();
+        match id.parse::<u32>() {
+            Ok(number) => Season(number.to_string()),
+            Err(_) => Season(id),
+        }
+    }
+
+    /// The newest season known to this crate.
+    pub fn latest_known() -> Self {
+        Season::new(KNOWN_SEASONS[KNOWN_SEASONS.len() - 1].id)
+    }
+
+    pub fn id(&self) -> &str {
+        &self.0
+    }
+
+    pub fn number(&self) -> Option<u32> {
+        self.0.parse().ok()
+    }
+
+    pub fn info(&self) -> Option<&'static SeasonInfo> {
+        KNOWN_SEASONS.iter().find(|season| season.id == self.0)
+    }
+
+    /// The name of the season, "Season {id}" for seasons unknown to this crate.
+    pub fn name(&self) -> String {
+        self.info().map_or_else(|| format!("Season {}", self.0), |info| info.name.to_string())
+    }
+
+    /// Whether the season is known to have ended.
+    pub fn has_ended(&self) -> bool {
+        self.info().is_some_and(|info| info.ended.is_some())
+    }
+}
+
+impl Display for Season {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<APIstring> for Season {
+    fn from(value: APIstring) -> Self {
+        Season::new(value)
+    }
+}
+
+impl From<&str> for Season {
+    fn from(value: &str) -> Self {
+        Season::new(value)
+    }
+}
+
+impl From<Season> for APIstring {
+    fn from(value: Season) -> Self {
+        value.0
+    }
+}
+
+impl PartialOrd for Season {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Season {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.number(), other.number()) {
+            (Some(left), Some(right)) => left.cmp(&right),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => self.0.cmp(&other.0),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 
 use serde::{Serialize, Deserialize};
 
-use crate::models::{common::{APIfloat, APIint, APIstring}, packet::Packet};
+use crate::models::{common::{APIfloat, APIint, APIstring, APItimestamp}, packet::Packet};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct LeagueEndContextUser {
@@ -91,7 +91,7 @@ pub struct LeagueStreamRecords {
     #[serde(rename = "replayid")]
     pub replay_id: APIstring,
     pub stream: APIstring,
-    pub ts: APIstring,
+    pub ts: APItimestamp,
     pub user: LeagueStreamUser
 }
 
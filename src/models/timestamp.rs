@@ -0,0 +1,115 @@
+use std::{convert::Infallible, fmt::Display, ops::Deref, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use super::common::APIstring;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+/// A date sent by the tetr.io api, kept as the ISO 8601 string the api sent (e.g. `2024-08-01T12:00:00.000Z`).
+/// Dates in this format sort chronologically. Typed accessors are available with the `chrono` feature.
+pub struct Timestamp(APIstring);
+
+impl Timestamp {
+    pub fn new(value: impl Into<APIstring>) -> Self {
+        Timestamp(value.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> APIstring {
+        self.0
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Timestamp {
+    pub fn now() -> Self {
+        Self::from(chrono::Utc::now())
+    }
+
+    pub fn from_millis(millis: i64) -> Option<Self> {
+        chrono::DateTime::from_timestamp_millis(millis).map(Self::from)
+    }
+
+    /// The parsed date, None if the api sent something which isn't an RFC 3339 date.
+    pub fn datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::parse_from_rfc3339(&self.0).ok().map(|date| date.with_timezone(&chrono::Utc))
+    }
+
+    pub fn millis(&self) -> Option<i64> {
+        self.datetime().map(|date| date.timestamp_millis())
+    }
+
+    /// The time elapsed since this date, negative if the date is in the future.
+    pub fn elapsed(&self) -> Option<chrono::Duration> {
+        self.datetime().map(|date| chrono::Utc::now() - date)
+    }
+
+    /// The amount of whole days elapsed since this date.
+    pub fn days_ago(&self) -> Option<i64> {
+        self.elapsed().map(|elapsed| elapsed.num_days())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for Timestamp {
+    /// Formats the date the way the api does.
+    fn from(value: chrono::DateTime<chrono::Utc>) -> Self {
+        Timestamp(value.to_rfc3339_opts(chrono::SecondsFormat::Millis, true))
+    }
+}
+
+impl Deref for Timestamp {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Timestamp {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<APIstring> for Timestamp {
+    fn from(value: APIstring) -> Self {
+        Timestamp(value)
+    }
+}
+
+impl From<&str> for Timestamp {
+    fn from(value: &str) -> Self {
+        Timestamp(value.to_string())
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Timestamp::from(s))
+    }
+}
+
+impl PartialEq<str> for Timestamp {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Timestamp {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -3,6 +3,7 @@
 
 use std::sync::Arc;
 
+use crate::models::common::APItimestamp;
 use crate::models::packet::Packet;
 use crate::models::users::user_role::UserRole;
 use serde::{Deserialize, Serialize};
@@ -18,7 +19,7 @@ pub struct XpUser {
     /// The user's role (one of "anon", "user", "bot", "halfmod", "mod", "admin", "sysop").
     pub role: UserRole,
     /// When the user account was created. If not set, this account was created before join dates were recorded.
-    pub ts: Option<Arc<str>>,
+    pub ts: Option<APItimestamp>,
     /// The user's ISO 3166-1 country code, or null if hidden/unknown. Some vanity flags exist.
     pub country: Option<Arc<str>>,
     /// Whether this user is currently supporting TETR.IO <3
@@ -1,9 +1,225 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::common::{APIfloat, APIint, APIstring};
+use crate::models::{common::{APIfloat, APIint, APIstring, APItimestamp}, general::achivement_info::AchievementCutoffs};
 use std::collections::HashMap;
 
+/// The medal obtained in an achievement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum AchievementRank {
+    None,
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+    Diamond,
+    /* Given to achievements which are simply issued (rank type ISSUE). */
+    Issued,
+    Unknown(u8),
+}
+
+impl AchievementRank {
+    /// The medals which can be earned through cutoffs, from the lowest to the highest.
+    pub const TIERS: [AchievementRank; 5] = [Self::Bronze, Self::Silver, Self::Gold, Self::Platinum, Self::Diamond];
+
+    /// The medal right above this one, None for Diamond and non tiered ranks.
+    pub fn next(&self) -> Option<AchievementRank> {
+        match self {
+            Self::None => Some(Self::Bronze),
+            Self::Bronze => Some(Self::Silver),
+            Self::Silver => Some(Self::Gold),
+            Self::Gold => Some(Self::Platinum),
+            Self::Platinum => Some(Self::Diamond),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Bronze => "Bronze",
+            Self::Silver => "Silver",
+            Self::Gold => "Gold",
+            Self::Platinum => "Platinum",
+            Self::Diamond => "Diamond",
+            Self::Issued => "Issued",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
+}
+
+impl From<u8> for AchievementRank {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::Bronze,
+            2 => Self::Silver,
+            3 => Self::Gold,
+            4 => Self::Platinum,
+            5 => Self::Diamond,
+            100 => Self::Issued,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<AchievementRank> for u8 {
+    fn from(value: AchievementRank) -> Self {
+        match value {
+            AchievementRank::None => 0,
+            AchievementRank::Bronze => 1,
+            AchievementRank::Silver => 2,
+            AchievementRank::Gold => 3,
+            AchievementRank::Platinum => 4,
+            AchievementRank::Diamond => 5,
+            AchievementRank::Issued => 100,
+            AchievementRank::Unknown(other) => other,
+        }
+    }
+}
+
+/// How the medals of an achievement are given out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum AchievementRankType {
+    /* Ranked by percentile cutoffs. */
+    Percentile,
+    /* Always issued. */
+    Issue,
+    /* Ranked by QUICK PLAY floor. */
+    Zenith,
+    /* Ranked by more lenient percentile cutoffs. */
+    PercentileLax,
+    /* Ranked by very lenient percentile cutoffs. */
+    PercentileVeryLax,
+    /* Ranked by extremely lenient percentile cutoffs. */
+    PercentileMegaLax,
+    Unknown(u8),
+}
+
+impl From<u8> for AchievementRankType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Percentile,
+            2 => Self::Issue,
+            3 => Self::Zenith,
+            4 => Self::PercentileLax,
+            5 => Self::PercentileVeryLax,
+            6 => Self::PercentileMegaLax,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<AchievementRankType> for u8 {
+    fn from(value: AchievementRankType) -> Self {
+        match value {
+            AchievementRankType::Percentile => 1,
+            AchievementRankType::Issue => 2,
+            AchievementRankType::Zenith => 3,
+            AchievementRankType::PercentileLax => 4,
+            AchievementRankType::PercentileVeryLax => 5,
+            AchievementRankType::PercentileMegaLax => 6,
+            AchievementRankType::Unknown(other) => other,
+        }
+    }
+}
+
+/// How the score of an achievement should be displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum AchievementValueType {
+    None,
+    Number,
+    /* A time in milliseconds. */
+    Time,
+    /* A time in milliseconds, where a higher time is better. */
+    TimeInverse,
+    /* An altitude, the additional data (a) holds the floor. */
+    Floor,
+    Issue,
+    /* A number, where a lower number is better. */
+    NumberInverse,
+    Unknown(u8),
+}
+
+impl From<u8> for AchievementValueType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::Number,
+            2 => Self::Time,
+            3 => Self::TimeInverse,
+            4 => Self::Floor,
+            5 => Self::Issue,
+            6 => Self::NumberInverse,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<AchievementValueType> for u8 {
+    fn from(value: AchievementValueType) -> Self {
+        match value {
+            AchievementValueType::None => 0,
+            AchievementValueType::Number => 1,
+            AchievementValueType::Time => 2,
+            AchievementValueType::TimeInverse => 3,
+            AchievementValueType::Floor => 4,
+            AchievementValueType::Issue => 5,
+            AchievementValueType::NumberInverse => 6,
+            AchievementValueType::Unknown(other) => other,
+        }
+    }
+}
+
+/// How an achievement counts towards the Achievement Rating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum AchievementArType {
+    /* The achievement does not give any AR. */
+    Unranked,
+    /* The achievement gives AR depending on its medal. */
+    Ranked,
+    /* The achievement gives AR depending on its medal and its leaderboard position. */
+    Competitive,
+    Unknown(u8),
+}
+
+impl From<u8> for AchievementArType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Unranked,
+            1 => Self::Ranked,
+            2 => Self::Competitive,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<AchievementArType> for u8 {
+    fn from(value: AchievementArType) -> Self {
+        match value {
+            AchievementArType::Unranked => 0,
+            AchievementArType::Ranked => 1,
+            AchievementArType::Competitive => 2,
+            AchievementArType::Unknown(other) => other,
+        }
+    }
+}
 
+/// How far an achievement is from its next medal, see [`Achievement::progress`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TierProgress {
+    pub current: AchievementRank,
+    pub next: AchievementRank,
+    /* The score required for the next medal. */
+    pub target: APIfloat,
+    /* The score still missing to get the next medal. */
+    pub remaining: APIfloat,
+    /* The progress (0~1) from the current medal to the next one. */
+    pub progress: APIfloat,
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Achievement {
@@ -25,11 +241,11 @@ pub struct Achievement {
     /* The order of this achievement in its category. */
     pub o: Option<APIint>,
     /*  The rank type of this achievement */
-    pub rt: u8,
-    /* The achieved score for this achievement (replaces V) */
-    pub vt: u8,
+    pub rt: AchievementRankType,
+    /* The type of value shown for this achievement (replaces V) */
+    pub vt: AchievementValueType,
     /* The AR type of this achievement */
-    pub art: u8,
+    pub art: AchievementArType,
     /* The minimum score required to obtain the achievement. */
     pub min: APIint,
     /* The amount of decimal placed to show. */
@@ -41,13 +257,13 @@ pub struct Achievement {
     /* Additional data (see score). */
     pub a: Option<APIfloat>,
     /* The time the achievement was updated. */
-    pub t: Option<APIstring>,
+    pub t: Option<APItimestamp>,
     /* The zero-indexed position in the achievement's leaderboards. */
     pub pos: Option<APIint>,
     /* The total amount of players who have this achievement (with a value of min or higher). */
     pub total: Option<APIint>,
     /* The rank of the achievement */
-    pub rank: Option<u8>,
+    pub rank: Option<AchievementRank>,
     pub n: Option<APIstring>,
     pub nolb: bool,
     pub stub: Option<bool>,
@@ -58,3 +274,37 @@ pub struct Achievement {
 }
 
 pub type AchievementsSummary = Vec<Achievement>;
+
+impl Achievement {
+    /// The medal obtained, [`AchievementRank::None`] if the achievement was not obtained.
+    pub fn rank(&self) -> AchievementRank {
+        self.rank.unwrap_or(AchievementRank::None)
+    }
+
+    /// The progress towards the next medal which can be obtained through the cutoffs of the achievement,
+    /// as returned by `fetch_achievement_info`.
+    /// Returns None when the achievement has no score yet, or when no higher medal is available.
+    ///
+    /// Scores and cutoffs are compared as sent by the API, where a higher value is always better.
+    pub fn progress(&self, cutoffs: &AchievementCutoffs) -> Option<TierProgress> {
+        let value = self.v?;
+        let current = self.rank();
+        let mut next = current.next()?;
+        let target = loop {
+            if let Some(target) = cutoffs.get(next) {
+                break target;
+            }
+            next = next.next()?;
+        };
+        let floor = cutoffs.get(current).unwrap_or(self.min as APIfloat);
+        let progress = if target > floor { (value - floor) / (target - floor) } else { 1.0 };
+
+        Some(TierProgress {
+            current,
+            next,
+            target,
+            remaining: (target - value).max(0.0),
+            progress: progress.clamp(0.0, 1.0),
+        })
+    }
+}
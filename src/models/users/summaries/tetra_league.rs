@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::models::{common::{APIfloat, APIint, APIstring}, users::user_rank::UserRank};
+use crate::models::{common::{APIfloat, APIint, APIstring}, season::Season, users::user_rank::UserRank};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct LeagueSummaryPast {
@@ -10,7 +10,7 @@ pub struct LeagueSummaryPast {
     #[serde(flatten)]
     pub ignored_fields: HashMap<String, serde_json::Value>,
     /* The season ID. */
-    pub season: APIstring,
+    pub season: Season,
     /* The username the user had at the time. */
     pub username: APIstring,
     /* The country the user represented at the time. */
@@ -32,9 +32,9 @@ pub struct LeagueSummaryPast {
     /* This user's final GLIXARE score (a % chance of beating an average player). */
     pub gxe: Option<APIfloat>,
     /* This user's final letter rank. z is unranked. */
-    pub rank: APIstring,
+    pub rank: UserRank,
     /* This user's highest achieved rank in the season. */
-    pub bestrank: Option<APIstring>,
+    pub bestrank: Option<UserRank>,
     /* This user's average APM (attack per minute) over the last 10 games in the season. */
     pub apm: APIfloat,
     /* This user's average PPS (pieces per second) over the last 10 games in the season. */
@@ -88,5 +88,5 @@ pub struct LeagueSummary {
     pub next_at: Option<APIint>,
     /* The position of the worst player in the user's current rank, dip below them to go down a rank. -1 if unranked (or the worst rank). */
     pub prev_at: Option<APIint>,
-    pub past: Option<HashMap<APIstring, LeagueSummaryPast>>
+    pub past: Option<HashMap<Season, LeagueSummaryPast>>
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::common::APIint;
+use crate::models::{common::APIint, users::summaries::achievements::AchievementRank};
 use std::collections::HashMap;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -9,22 +9,72 @@ pub struct UserArCounts {
     #[serde(flatten)]
     pub ignored_fields: HashMap<String, serde_json::Value>,
     #[serde(rename="1")]
-    bronze: Option<APIint>,
+    pub bronze: Option<APIint>,
     #[serde(rename="2")]
-    silver: Option<APIint>,
+    pub silver: Option<APIint>,
     #[serde(rename="3")]
-    gold: Option<APIint>,
+    pub gold: Option<APIint>,
     #[serde(rename="4")]
-    platinum: Option<APIint>,
+    pub platinum: Option<APIint>,
     #[serde(rename="5")]
-    diamond: Option<APIint>,
+    pub diamond: Option<APIint>,
     #[serde(rename="100")]
-    issued: Option<APIint>,
+    pub issued: Option<APIint>,
     
-    t100: Option<APIint>,
-    t50: Option<APIint>,
-    t25: Option<APIint>,
-    t10: Option<APIint>,
-    t5: Option<APIint>,
-    t3: Option<APIint>,
+    pub t100: Option<APIint>,
+    pub t50: Option<APIint>,
+    pub t25: Option<APIint>,
+    pub t10: Option<APIint>,
+    pub t5: Option<APIint>,
+    pub t3: Option<APIint>,
+}
+
+impl UserArCounts {
+    /// The leaderboard placements rewarded by competitive achievements, from the lowest to the highest.
+    pub const TOP_PLACEMENTS: [u8; 6] = [100, 50, 25, 10, 5, 3];
+
+    /// The amount of achievements with a given medal.
+    pub fn medal(&self, rank: AchievementRank) -> APIint {
+        match rank {
+            AchievementRank::Bronze => self.bronze,
+            AchievementRank::Silver => self.silver,
+            AchievementRank::Gold => self.gold,
+            AchievementRank::Platinum => self.platinum,
+            AchievementRank::Diamond => self.diamond,
+            AchievementRank::Issued => self.issued,
+            _ => None,
+        }.unwrap_or(0)
+    }
+
+    /// The amount of competitive achievements placed in a given top (100, 50, 25, 10, 5 or 3).
+    pub fn top(&self, placement: u8) -> APIint {
+        match placement {
+            100 => self.t100,
+            50 => self.t50,
+            25 => self.t25,
+            10 => self.t10,
+            5 => self.t5,
+            3 => self.t3,
+            _ => None,
+        }.unwrap_or(0)
+    }
+
+    /// The amount of achievements for every medal, from Bronze to Diamond, followed by the issued ones.
+    pub fn medal_breakdown(&self) -> Vec<(AchievementRank, APIint)> {
+        AchievementRank::TIERS
+            .iter()
+            .chain([AchievementRank::Issued].iter())
+            .map(|rank| (*rank, self.medal(*rank)))
+            .collect()
+    }
+
+    /// The amount of competitive achievements for every top placement, from top 100 to top 3.
+    pub fn top_breakdown(&self) -> Vec<(u8, APIint)> {
+        Self::TOP_PLACEMENTS.iter().map(|placement| (*placement, self.top(*placement))).collect()
+    }
+
+    /// The amount of achievements with a medal, issued ones included.
+    pub fn total_medals(&self) -> APIint {
+        self.medal_breakdown().iter().map(|(_, count)| count).sum()
+    }
 }
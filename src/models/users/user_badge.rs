@@ -1,18 +1,36 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::models::common::APIstring;
+use crate::models::common::{APIstring, APItimestamp};
 use std::collections::HashMap;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct UserBadge {
 
     #[serde(flatten)]
     pub ignored_fields: HashMap<String, serde_json::Value>,
     pub id: APIstring,
     pub label: APIstring,
-    pub ts: Option<serde_json::Value>,
+    pub ts: Option<BadgeTimestamp>,
     pub group: Option<APIstring>,
     pub desc: Option<APIstring>,
     pub global: Option<bool>,
 }
+
+/// When a badge was awarded. Some badges hide the date, and the api sends `false` instead.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum BadgeTimestamp {
+    Date(APItimestamp),
+    Other(serde_json::Value),
+}
+
+impl BadgeTimestamp {
+    /// The date the badge was awarded, None if it is not shown.
+    pub fn date(&self) -> Option<&APItimestamp> {
+        match self {
+            Self::Date(date) => Some(date),
+            Self::Other(_) => None,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use crate::{http::parameters::value_bound_query::Prisecter, models::{common::{APIfloat, APIint, APIstring}, packet::Packet}};
+use crate::{http::parameters::value_bound_query::Prisecter, models::{common::{APIfloat, APIint, APIstring}, packet::Packet, season::Season}};
 
 use super::user_rank::UserRank;
 
@@ -11,7 +11,7 @@ pub struct LeaderboardUser {
     /* The user's internal ID. */
     pub _id: APIstring,
     /* The season ID. */
-    pub season: APIstring,
+    pub season: Season,
     /* The username the user had at the time. */
     pub username: APIstring,
     /* The country the user represented at the time. */
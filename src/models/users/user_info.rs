@@ -1,6 +1,6 @@
 
 use serde::{Deserialize, Serialize};
-use crate::models::common::{APIint, APIintarray, APIstring};
+use crate::models::common::{APIint, APIintarray, APIstring, APItimestamp};
 use crate::models::packet::Packet;
 use crate::models::users::user_badge::UserBadge;
 use crate::models::users::user_connections::UserConnections;
@@ -43,7 +43,7 @@ pub struct UserInfo {
     pub id: APIstring,
     pub username: APIstring,
     pub role: UserRole,
-    pub ts: Option<APIstring>,
+    pub ts: Option<APItimestamp>,
     pub botmaster: Option<APIstring>,
     pub badges: Box<[UserBadge]>,
     pub xp: f64,
@@ -1,7 +1,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{http::parameters::value_bound_query::Prisecter, models::{common::{APIfloat, APIint, APIstring}, packet::Packet}};
+use crate::{http::parameters::value_bound_query::Prisecter, models::{common::{APIfloat, APIint, APIstring, APItimestamp}, packet::Packet}};
 
 use super::{user_achievements::UserArCounts, user_rank::UserRank, user_role::UserRole};
 use std::collections::HashMap;
@@ -46,7 +46,7 @@ pub struct LeaderboardUser {
     pub id: APIstring,
     pub username: APIstring,
     pub role: UserRole,
-    pub ts: Option<APIstring>,
+    pub ts: Option<APItimestamp>,
     pub xp: APIfloat,
     pub country: Option<APIstring>,
     pub supporter: Option<bool>,
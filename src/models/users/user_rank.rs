@@ -68,3 +68,166 @@ impl std::fmt::Display for UserRank {
         }
     }
 }
+
+/// The error returned when a string isn't a known letter rank.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseUserRankError(pub String);
+
+impl std::fmt::Display for ParseUserRankError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown rank: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseUserRankError {}
+
+impl UserRank {
+    /// Every letter rank obtainable in TETRA LEAGUE, from the worst (D) to the best (X+).
+    pub const RANKED: [UserRank; 18] = [
+        UserRank::D,
+        UserRank::DPlus,
+        UserRank::CMinus,
+        UserRank::C,
+        UserRank::CPlus,
+        UserRank::BMinus,
+        UserRank::B,
+        UserRank::BPlus,
+        UserRank::AMinus,
+        UserRank::A,
+        UserRank::APlus,
+        UserRank::SMinus,
+        UserRank::S,
+        UserRank::SPlus,
+        UserRank::SS,
+        UserRank::U,
+        UserRank::X,
+        UserRank::XPlus,
+    ];
+
+    /// Iterates over every obtainable letter rank, from the worst (D) to the best (X+).
+    /// Z (unranked) is not included.
+    pub fn iter() -> impl DoubleEndedIterator<Item = UserRank> {
+        Self::RANKED.into_iter()
+    }
+
+    /// The position of the rank in [`UserRank::RANKED`], or None for Z and unknown ranks.
+    pub fn ranked_index(&self) -> Option<usize> {
+        Self::RANKED.iter().position(|rank| rank == self)
+    }
+
+    /// Whether this is an obtainable letter rank (not Z and not an unknown rank).
+    pub fn is_ranked(&self) -> bool {
+        self.ranked_index().is_some()
+    }
+
+    /// The rank directly above this one, or None for X+, Z and unknown ranks.
+    pub fn next_rank(&self) -> Option<UserRank> {
+        self.ranked_index().and_then(|index| Self::RANKED.get(index + 1)).cloned()
+    }
+
+    /// The rank directly below this one, or None for D, Z and unknown ranks.
+    pub fn previous_rank(&self) -> Option<UserRank> {
+        self.ranked_index().and_then(|index| index.checked_sub(1)).map(|index| Self::RANKED[index].clone())
+    }
+
+    /// The identifier used by the API for this rank (e.g. "s+").
+    pub fn id(&self) -> &str {
+        match self {
+            UserRank::XPlus => "x+",
+            UserRank::X => "x",
+            UserRank::U => "u",
+            UserRank::SS => "ss",
+            UserRank::SPlus => "s+",
+            UserRank::S => "s",
+            UserRank::SMinus => "s-",
+            UserRank::APlus => "a+",
+            UserRank::A => "a",
+            UserRank::AMinus => "a-",
+            UserRank::BPlus => "b+",
+            UserRank::B => "b",
+            UserRank::BMinus => "b-",
+            UserRank::CPlus => "c+",
+            UserRank::C => "c",
+            UserRank::CMinus => "c-",
+            UserRank::DPlus => "d+",
+            UserRank::D => "d",
+            UserRank::Z => "z",
+            UserRank::Unknown(rank) => rank,
+        }
+    }
+
+    /// The official colour of the rank as a 0xRRGGBB integer, or None for unknown ranks.
+    pub fn color(&self) -> Option<u32> {
+        match self {
+            UserRank::XPlus => Some(0x643C8D),
+            UserRank::X => Some(0xFF45FF),
+            UserRank::U => Some(0xFF3813),
+            UserRank::SS => Some(0xDB8B1F),
+            UserRank::SPlus => Some(0xD8AF0E),
+            UserRank::S => Some(0xE0A71B),
+            UserRank::SMinus => Some(0xB2972B),
+            UserRank::APlus => Some(0x1FA834),
+            UserRank::A => Some(0x46AD51),
+            UserRank::AMinus => Some(0x3BB687),
+            UserRank::BPlus => Some(0x4F99C0),
+            UserRank::B => Some(0x4F64C9),
+            UserRank::BMinus => Some(0x5650C7),
+            UserRank::CPlus => Some(0x552883),
+            UserRank::C => Some(0x733E8F),
+            UserRank::CMinus => Some(0x79558C),
+            UserRank::DPlus => Some(0x8E6091),
+            UserRank::D => Some(0x907591),
+            UserRank::Z => Some(0x828282),
+            UserRank::Unknown(_) => None,
+        }
+    }
+
+    /// The official colour of the rank formatted as "#RRGGBB", or None for unknown ranks.
+    pub fn color_hex(&self) -> Option<String> {
+        self.color().map(|color| format!("#{color:06X}"))
+    }
+
+    /// The URL of the rank icon hosted on tetr.io, see [`Urls::rank_icon`](crate::urls::Urls::rank_icon) for other hosts.
+    pub fn icon_url(&self) -> String {
+        crate::urls::Urls::default().rank_icon(self)
+    }
+}
+
+impl std::str::FromStr for UserRank {
+    type Err = ParseUserRankError;
+
+    /// Parses a rank case-insensitively, e.g. "s+", "S+" or "x".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lowercase = s.trim().to_lowercase();
+
+        Self::RANKED
+            .into_iter()
+            .chain(std::iter::once(UserRank::Z))
+            .find(|rank| rank.id() == lowercase)
+            .ok_or_else(|| ParseUserRankError(s.to_string()))
+    }
+}
+
+impl PartialOrd for UserRank {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UserRank {
+    /// Ranks are ordered from the worst to the best: unknown ranks, then Z (unranked), then D through X+.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        fn weight(rank: &UserRank) -> usize {
+            match rank {
+                UserRank::Unknown(_) => 0,
+                UserRank::Z => 1,
+                rank => rank.ranked_index().map_or(0, |index| index + 2),
+            }
+        }
+
+        match (self, other) {
+            (UserRank::Unknown(a), UserRank::Unknown(b)) => a.cmp(b),
+            _ => weight(self).cmp(&weight(other)),
+        }
+    }
+}
@@ -6,7 +6,7 @@
 use serde::{Deserialize, Serialize};
 
 use std::collections::HashMap;
-use crate::{http::parameters::{personal_user_records::GameMode, value_bound_query::Prisecter}, models::common::{APIfloat, APIint, APIintarray, APIsmallint, APIstring}};
+use crate::{http::parameters::{personal_user_records::GameMode, value_bound_query::Prisecter}, models::common::{APIfloat, APIint, APIintarray, APIsmallint, APIstring, APItimestamp}};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BlitzAggregateStats {
@@ -181,7 +181,7 @@ pub struct BlitzRecord {
     pub gamemode: GameMode,
     pub pb: bool,
     pub oncepb: bool,
-    pub ts: APIstring,
+    pub ts: APItimestamp,
     pub revolution: Option<APIstring>,
     pub user: Option<BlitzRecordUser>,
     pub otherusers: Vec<BlitzRecordUser>,
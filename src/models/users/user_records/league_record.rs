@@ -8,7 +8,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 
 
-use crate::{http::parameters::{personal_user_records::GameMode, value_bound_query::Prisecter}, models::{common::{APIArray, APIfloat, APIint, APIsmallint, APIstring}, users::user_rank::UserRank}};
+use crate::{http::parameters::{personal_user_records::GameMode, value_bound_query::Prisecter}, models::{common::{APIArray, APIfloat, APIint, APIsmallint, APIstring, APItimestamp}, users::user_rank::UserRank}};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 
@@ -152,7 +152,7 @@ pub struct LeagueRecord {
     pub gamemode: GameMode,
     pub pb: bool,
     pub oncepb: bool,
-    pub ts: APIstring,
+    pub ts: APItimestamp,
     pub revolution: Option<APIstring>,
     pub user: Option<LeagueRecordUser>,
     pub otherusers: Vec<LeagueRecordUser>,
@@ -30,4 +30,6 @@ pub type PersonalBlitzRecordPacket = Packet<PersonalUserRecords<BlitzRecord>>;
 pub type PersonalZenithRecordPacket = Packet<PersonalUserRecords<ZenithRecord>>;
 pub type PersonalZenithExRecordPacket = Packet<PersonalUserRecords<ZenithExRecord>>;
 pub type PersonalLeagueRecordPacket = Packet<PersonalUserRecords<LeagueRecord>>;
+/// A page of a records leaderboard, its records having the same shape as personal records.
+pub type RecordsLeaderboardPacket<T> = Packet<PersonalUserRecords<T>>;
 
@@ -6,7 +6,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use crate::{http::parameters::{personal_user_records::GameMode, value_bound_query::Prisecter}, models::common::{APIArray, APIfloat, APIint, APIintarray, APIsmallint, APIstring}};
+use crate::{http::parameters::{personal_user_records::GameMode, value_bound_query::Prisecter}, models::common::{APIArray, APIfloat, APIint, APIintarray, APIsmallint, APIstring, APItimestamp}};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ZenithAggregateStats {
@@ -161,12 +161,98 @@ pub struct ZenithRecordUser {
     pub supporter: bool
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum ZenithMod {
+    Expert,
+    #[serde(rename = "expert_reversed")]
+    ExpertReversed,
+    NoHold,
+    #[serde(rename = "nohold_reversed")]
+    NoHoldReversed,
+    Messy,
+    #[serde(rename = "messy_reversed")]
+    MessyReversed,
+    Gravity,
+    #[serde(rename = "gravity_reversed")]
+    GravityReversed,
+    Volatile,
+    #[serde(rename = "volatile_reversed")]
+    VolatileReversed,
+    DoubleHole,
+    #[serde(rename = "doublehole_reversed")]
+    DoubleHoleReversed,
+    Invisible,
+    #[serde(rename = "invisible_reversed")]
+    InvisibleReversed,
+    AllSpin,
+    #[serde(rename = "allspin_reversed")]
+    AllSpinReversed,
+    #[serde(untagged)]
+    Unknown(String)
+}
+
+impl ZenithMod {
+    /// Whether this is the reversed version of a mod.
+    pub fn is_reversed(&self) -> bool {
+        matches!(
+            self,
+            ZenithMod::ExpertReversed
+                | ZenithMod::NoHoldReversed
+                | ZenithMod::MessyReversed
+                | ZenithMod::GravityReversed
+                | ZenithMod::VolatileReversed
+                | ZenithMod::DoubleHoleReversed
+                | ZenithMod::InvisibleReversed
+                | ZenithMod::AllSpinReversed
+        )
+    }
+
+    /// The non-reversed version of this mod.
+    pub fn base(&self) -> ZenithMod {
+        match self {
+            ZenithMod::ExpertReversed => ZenithMod::Expert,
+            ZenithMod::NoHoldReversed => ZenithMod::NoHold,
+            ZenithMod::MessyReversed => ZenithMod::Messy,
+            ZenithMod::GravityReversed => ZenithMod::Gravity,
+            ZenithMod::VolatileReversed => ZenithMod::Volatile,
+            ZenithMod::DoubleHoleReversed => ZenithMod::DoubleHole,
+            ZenithMod::InvisibleReversed => ZenithMod::Invisible,
+            ZenithMod::AllSpinReversed => ZenithMod::AllSpin,
+            other => other.clone(),
+        }
+    }
+
+    /// The name of the tarot card representing this mod in game.
+    pub fn card_name(&self) -> &str {
+        match self {
+            ZenithMod::Expert => "The Emperor",
+            ZenithMod::ExpertReversed => "The Tyrant",
+            ZenithMod::NoHold => "Temperance",
+            ZenithMod::NoHoldReversed => "Asceticism",
+            ZenithMod::Messy => "Wheel of Fortune",
+            ZenithMod::MessyReversed => "Loaded Dice",
+            ZenithMod::Gravity => "The Tower",
+            ZenithMod::GravityReversed => "Freefall",
+            ZenithMod::Volatile => "Strength",
+            ZenithMod::VolatileReversed => "Last Stand",
+            ZenithMod::DoubleHole => "The Devil",
+            ZenithMod::DoubleHoleReversed => "Damnation",
+            ZenithMod::Invisible => "The Hermit",
+            ZenithMod::InvisibleReversed => "The Exile",
+            ZenithMod::AllSpin => "The Magician",
+            ZenithMod::AllSpinReversed => "The Warlock",
+            ZenithMod::Unknown(name) => name,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ZenithExtrasZenith {
 
     #[serde(flatten)]
     pub ignored_fields: HashMap<String, serde_json::Value>,
-    pub mods: APIArray<APIstring>
+    pub mods: APIArray<ZenithMod>
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -190,7 +276,7 @@ pub struct ZenithRecord {
     pub gamemode: GameMode,
     pub pb: bool,
     pub oncepb: bool,
-    pub ts: APIstring,
+    pub ts: APItimestamp,
     pub revolution: Option<APIstring>,
     pub user: Option<ZenithRecordUser>,
     pub otherusers: Vec<ZenithRecordUser>,
@@ -6,7 +6,9 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use crate::{http::parameters::{personal_user_records::GameMode, value_bound_query::Prisecter}, models::common::{APIArray, APIfloat, APIint, APIintarray, APIsmallint, APIstring}};
+use crate::{http::parameters::{personal_user_records::GameMode, value_bound_query::Prisecter}, models::common::{APIArray, APIfloat, APIint, APIintarray, APIsmallint, APIstring, APItimestamp}};
+
+use super::zenith_record::ZenithMod;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ZenithExAggregateStats {
@@ -164,7 +166,7 @@ pub struct ZenithExExtrasZenith {
 
     #[serde(flatten)]
     pub ignored_fields: HashMap<String, serde_json::Value>,
-    pub mods: APIArray<APIstring>
+    pub mods: APIArray<ZenithMod>
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -188,7 +190,7 @@ pub struct ZenithExRecord {
     pub gamemode: GameMode,
     pub pb: bool,
     pub oncepb: bool,
-    pub ts: APIstring,
+    pub ts: APItimestamp,
     pub revolution: Option<APIstring>,
     pub user: Option<ZenithExRecordUser>,
     pub otherusers: Vec<ZenithExRecordUser>,
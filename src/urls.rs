@@ -0,0 +1,274 @@
+use crate::models::{
+    common::APIint,
+    users::{
+        user_badge::UserBadge,
+        user_info::UserInfo,
+        user_rank::UserRank,
+        user_records::{
+            blitz_record::BlitzRecordUser, league_record::LeagueRecordUser, sprint_record::SprintRecordUser,
+            zenith_record::ZenithRecordUser, zenithex_record::ZenithExRecordUser,
+        },
+    },
+};
+
+/// The host serving the game, its assets and the user content.
+pub const TETRIO_HOST: &str = "https://tetr.io";
+/// The host serving the TETRA CHANNEL profiles.
+pub const TETRA_CHANNEL_HOST: &str = "https://ch.tetr.io";
+
+/// A user which has a profile picture, a banner and a country flag.
+pub trait UserAssets {
+    fn user_id(&self) -> &str;
+    fn username(&self) -> &str;
+    fn avatar_revision(&self) -> Option<APIint>;
+    fn banner_revision(&self) -> Option<APIint>;
+    fn country(&self) -> Option<&str>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Builds the URLs of the images and pages related to the API models.
+/// The hosts default to [`TETRIO_HOST`] and [`TETRA_CHANNEL_HOST`], but can be replaced to go through a proxy for example.
+pub struct Urls {
+    tetrio_host: String,
+    channel_host: String,
+}
+
+impl Default for Urls {
+    fn default() -> Self {
+        Self::new(TETRIO_HOST, TETRA_CHANNEL_HOST)
+    }
+}
+
+impl Urls {
+    pub fn new(tetrio_host: impl Into<String>, channel_host: impl Into<String>) -> Self {
+        Self {
+            tetrio_host: tetrio_host.into().trim_end_matches('/').to_string(),
+            channel_host: channel_host.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub fn with_tetrio_host(self, tetrio_host: impl Into<String>) -> Self {
+        Self::new(tetrio_host, self.channel_host)
+    }
+
+    pub fn with_channel_host(self, channel_host: impl Into<String>) -> Self {
+        Self::new(self.tetrio_host, channel_host)
+    }
+
+    pub fn tetrio_host(&self) -> &str {
+        &self.tetrio_host
+    }
+
+    pub fn channel_host(&self) -> &str {
+        &self.channel_host
+    }
+
+    /// The avatar of a user.
+    /// Users without an avatar revision (or with a revision of 0) never uploaded one, the default avatar is returned instead.
+    pub fn avatar(&self, user_id: &str, avatar_revision: Option<APIint>) -> String {
+        match avatar_revision {
+            Some(revision) if revision > 0 => {
+                format!("{}/user-content/avatars/{user_id}.jpg?rv={revision}", self.tetrio_host)
+            }
+            _ => self.default_avatar(),
+        }
+    }
+
+    /// The avatar shown for users who never uploaded one.
+    pub fn default_avatar(&self) -> String {
+        format!("{}/res/avatar.png", self.tetrio_host)
+    }
+
+    /// The banner of a user, None if the user never uploaded one.
+    pub fn banner(&self, user_id: &str, banner_revision: Option<APIint>) -> Option<String> {
+        match banner_revision {
+            Some(revision) if revision > 0 => {
+                Some(format!("{}/user-content/banners/{user_id}.jpg?rv={revision}", self.tetrio_host))
+            }
+            _ => None,
+        }
+    }
+
+    /// The icon of a badge, from its ID.
+    pub fn badge_icon(&self, badge_id: &str) -> String {
+        format!("{}/res/badges/{badge_id}.png", self.tetrio_host)
+    }
+
+    /// The flag of a country, from its ISO 3166-1 code (e.g. "FR").
+    pub fn country_flag(&self, country: &str) -> String {
+        format!("{}/res/flags/{}.png", self.tetrio_host, country.to_lowercase())
+    }
+
+    /// The icon of a TETRA LEAGUE rank.
+    pub fn rank_icon(&self, rank: &UserRank) -> String {
+        format!("{}/res/league-ranks/{}.png", self.tetrio_host, rank.id())
+    }
+
+    /// The TETRA CHANNEL profile of a user, from their username or User ID.
+    pub fn profile(&self, user: &str) -> String {
+        format!("{}/u/{}", self.channel_host, user.to_lowercase())
+    }
+
+    /// The in-game link to a replay.
+    pub fn replay(&self, replay_id: &str) -> String {
+        format!("{}/#R:{replay_id}", self.tetrio_host)
+    }
+
+    /// The in-game link to a record.
+    pub fn record(&self, record_id: &str) -> String {
+        format!("{}/#r:{record_id}", self.tetrio_host)
+    }
+
+    pub fn user_avatar(&self, user: &impl UserAssets) -> String {
+        self.avatar(user.user_id(), user.avatar_revision())
+    }
+
+    pub fn user_banner(&self, user: &impl UserAssets) -> Option<String> {
+        self.banner(user.user_id(), user.banner_revision())
+    }
+
+    /// The flag of a user, None if the user did not set a country.
+    pub fn user_flag(&self, user: &impl UserAssets) -> Option<String> {
+        user.country().map(|country| self.country_flag(country))
+    }
+
+    pub fn user_profile(&self, user: &impl UserAssets) -> String {
+        self.profile(user.username())
+    }
+
+    pub fn badge(&self, badge: &UserBadge) -> String {
+        self.badge_icon(&badge.id)
+    }
+}
+
+impl UserAssets for UserInfo {
+    fn user_id(&self) -> &str {
+        &self.id
+    }
+
+    fn username(&self) -> &str {
+        &self.username
+    }
+
+    fn avatar_revision(&self) -> Option<APIint> {
+        self.avatar_revision
+    }
+
+    fn banner_revision(&self) -> Option<APIint> {
+        self.banner_revision
+    }
+
+    fn country(&self) -> Option<&str> {
+        self.country.as_deref()
+    }
+}
+
+impl UserAssets for SprintRecordUser {
+    fn user_id(&self) -> &str {
+        &self.id
+    }
+
+    fn username(&self) -> &str {
+        &self.username
+    }
+
+    fn avatar_revision(&self) -> Option<APIint> {
+        self.avatar_revision
+    }
+
+    fn banner_revision(&self) -> Option<APIint> {
+        self.banner_revision
+    }
+
+    fn country(&self) -> Option<&str> {
+        self.country.as_deref()
+    }
+}
+
+impl UserAssets for BlitzRecordUser {
+    fn user_id(&self) -> &str {
+        &self.id
+    }
+
+    fn username(&self) -> &str {
+        &self.username
+    }
+
+    fn avatar_revision(&self) -> Option<APIint> {
+        self.avatar_revision
+    }
+
+    fn banner_revision(&self) -> Option<APIint> {
+        self.banner_revision
+    }
+
+    fn country(&self) -> Option<&str> {
+        self.country.as_deref()
+    }
+}
+
+impl UserAssets for ZenithRecordUser {
+    fn user_id(&self) -> &str {
+        &self.id
+    }
+
+    fn username(&self) -> &str {
+        &self.username
+    }
+
+    fn avatar_revision(&self) -> Option<APIint> {
+        self.avatar_revision
+    }
+
+    fn banner_revision(&self) -> Option<APIint> {
+        self.banner_revision
+    }
+
+    fn country(&self) -> Option<&str> {
+        self.country.as_deref()
+    }
+}
+
+impl UserAssets for ZenithExRecordUser {
+    fn user_id(&self) -> &str {
+        &self.id
+    }
+
+    fn username(&self) -> &str {
+        &self.username
+    }
+
+    fn avatar_revision(&self) -> Option<APIint> {
+        self.avatar_revision
+    }
+
+    fn banner_revision(&self) -> Option<APIint> {
+        self.banner_revision
+    }
+
+    fn country(&self) -> Option<&str> {
+        self.country.as_deref()
+    }
+}
+
+impl UserAssets for LeagueRecordUser {
+    fn user_id(&self) -> &str {
+        &self.id
+    }
+
+    fn username(&self) -> &str {
+        &self.username
+    }
+
+    fn avatar_revision(&self) -> Option<APIint> {
+        self.avatar_revision
+    }
+
+    fn banner_revision(&self) -> Option<APIint> {
+        self.banner_revision
+    }
+
+    fn country(&self) -> Option<&str> {
+        self.country.as_deref()
+    }
+}
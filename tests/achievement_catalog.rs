@@ -0,0 +1,103 @@
+mod common;
+
+use std::time::Duration;
+
+use serde_json::json;
+use tetrio_api::http::{
+    cached_client::{CachedClient, ACHIEVEMENT_CATALOG_CONCURRENCY},
+    caches::noop_cache::NoopCache,
+    profile::PartFailure,
+    rate_limiters::local::LocalRateLimiter,
+};
+use tetrio_api::models::general::achievement_catalog::AchievementCatalog;
+use tetrio_api::models::users::user_info::UserInfo;
+
+use common::{failure, parse, success, user_info, FakeClient};
+
+fn achievement(k: i64, category: &str, o: i64, hidden: bool, event: Option<&str>) -> serde_json::Value {
+    json!({
+        "k": k, "category": category, "name": format!("achievement {k}"), "object": "", "desc": "",
+        "o": o, "rt": 1, "vt": 1, "art": 1, "min": 0, "deci": 0, "hidden": hidden,
+        "v": null, "a": null, "t": null, "pos": null, "total": null, "rank": null, "n": null,
+        "nolb": false, "stub": null, "disabled": null, "event": event, "event_past": null
+    })
+}
+
+fn info(k: i64, category: &str, o: i64, hidden: bool, event: Option<&str>) -> serde_json::Value {
+    let cutoffs = json!({ "total": 10, "diamond": 5.0, "platinum": 4.0, "gold": 3.0, "silver": 2.0, "bronze": 1.0 });
+
+    json!({ "achievement": achievement(k, category, o, hidden, event), "leaderboard": [], "cutoffs": cutoffs })
+}
+
+fn catalog_client() -> FakeClient {
+    FakeClient::new(|request| match request.path.as_str() {
+        "/api/achievements/1" => Ok(success(info(1, "solo", 2, false, None))),
+        "/api/achievements/2" => Ok(success(info(2, "solo", 1, true, None))),
+        "/api/achievements/3" => Ok(success(info(3, "event", 1, false, Some("halloween")))),
+        "/api/achievements/5" => Err(std::io::Error::other("connection reset")),
+        _ => Ok(failure("No such achievement")),
+    })
+}
+
+#[tokio::test]
+async fn catalog() {
+    let fake = catalog_client();
+    let client = CachedClient::with_rate_limiter(fake.clone(), NoopCache, LocalRateLimiter::new(Duration::ZERO));
+
+    let (mut catalog, failures) = client.fetch_achievement_catalog([1, 3]).await;
+    assert_eq!(catalog.len(), 2);
+    assert!(failures.is_empty());
+
+    let failures = client.refresh_achievement_catalog(&mut catalog, [1, 2, 3, 4, 5, 2]).await;
+    assert_eq!(failures.keys().copied().collect::<Vec<_>>(), vec![4, 5]);
+    assert!(matches!(&failures[&4], PartFailure::Unsuccessful(Some(msg)) if msg == "No such achievement"));
+    assert!(matches!(&failures[&5], PartFailure::Request(_)));
+    assert_eq!(catalog.ids().collect::<Vec<_>>(), vec![1, 2, 3]);
+    // The achievements already in the catalog are fetched again, each ID once.
+    assert_eq!(fake.request_count(), 2 + 5);
+
+    assert_eq!(catalog.categories(), vec!["event", "solo"]);
+    let solo = catalog.category("solo").iter().map(|achievement| achievement.k).collect::<Vec<_>>();
+    assert_eq!(solo, vec![2, 1]);
+    assert_eq!(catalog.visible().count(), 2);
+    assert_eq!(catalog.events().map(|(event, _)| event.as_str()).collect::<Vec<_>>(), vec!["halloween"]);
+    assert_eq!(catalog.cutoffs(1).unwrap().total, 10);
+
+    let snapshot: AchievementCatalog = serde_json::from_str(&serde_json::to_string(&catalog).unwrap()).unwrap();
+    assert_eq!(snapshot.len(), 3);
+    assert_eq!(snapshot.missing_ids([3, 5, 5]), vec![5]);
+    assert_eq!(snapshot.category("solo").iter().map(|achievement| achievement.k).collect::<Vec<_>>(), vec![2, 1]);
+
+    let user: UserInfo = parse(user_info("user", json!({ "achievements": [3, 9, 1] })));
+    let featured = snapshot.featured(&user).iter().map(|achievement| achievement.k).collect::<Vec<_>>();
+    assert_eq!(featured, vec![3, 1]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn catalog_requests_are_bounded() {
+    let fake = catalog_client().with_delay(Duration::from_secs(1));
+    let client = CachedClient::with_rate_limiter(fake.clone(), NoopCache, LocalRateLimiter::new(Duration::ZERO));
+
+    let failures = client.refresh_achievement_catalog(&mut AchievementCatalog::new(), 1..=20).await;
+
+    assert_eq!(failures.len(), 17);
+    assert_eq!(fake.request_count(), 20);
+    assert_eq!(fake.max_in_flight(), ACHIEVEMENT_CATALOG_CONCURRENCY);
+}
+
+#[test]
+fn categories_follow_replaced_achievements() {
+    let mut catalog = AchievementCatalog::new();
+    for (k, category, o) in [(1, "solo", 2), (2, "solo", 1), (3, "event", 1)] {
+        catalog.insert(parse(info(k, category, o, false, None)));
+    }
+
+    catalog.insert(parse(info(2, "event", 0, false, None)));
+    let ks = |category: &str| catalog.category(category).iter().map(|achievement| achievement.k).collect::<Vec<_>>();
+    assert_eq!(ks("event"), vec![2, 3]);
+    assert_eq!(ks("solo"), vec![1]);
+
+    catalog.remove(1);
+    assert_eq!(catalog.categories(), vec!["event"]);
+    assert!(catalog.category("solo").is_empty());
+}
@@ -0,0 +1,66 @@
+use serde_json::json;
+use tetrio_api::models::general::achivement_info::AchievementCutoffs;
+use tetrio_api::models::users::{
+    summaries::achievements::{Achievement, AchievementArType, AchievementRank, AchievementRankType, AchievementValueType},
+    user_achievements::UserArCounts,
+};
+
+fn get_achievement(v: Option<f64>, rank: Option<u8>) -> Achievement {
+    serde_json::from_value(json!({
+        "k": 2, "category": "competitive", "name": "Sprinter", "object": "40 LINES",
+        "desc": "Clear 40 lines", "o": 1, "rt": 1, "vt": 2, "art": 9, "min": 0, "deci": 3,
+        "hidden": false, "v": v, "a": null, "t": null, "pos": null, "total": null,
+        "rank": rank, "n": null, "nolb": false, "stub": null, "disabled": null,
+        "event": null, "event_past": null
+    }))
+    .unwrap()
+}
+
+fn get_cutoffs() -> AchievementCutoffs {
+    serde_json::from_value(json!({
+        "total": 1000, "diamond": 100.0, "platinum": 80.0, "gold": null, "silver": 40.0, "bronze": 20.0
+    }))
+    .unwrap()
+}
+
+#[test]
+fn achievement_types_are_typed() {
+    let achievement = get_achievement(Some(50.0), Some(2));
+
+    assert_eq!(achievement.rt, AchievementRankType::Percentile);
+    assert_eq!(achievement.vt, AchievementValueType::Time);
+    assert_eq!(achievement.art, AchievementArType::Unknown(9));
+    assert_eq!(achievement.rank(), AchievementRank::Silver);
+
+    let value = serde_json::to_value(&achievement).unwrap();
+    assert_eq!(value["rt"], 1);
+    assert_eq!(value["art"], 9);
+    assert_eq!(value["rank"], 2);
+}
+
+#[test]
+fn tier_progress() {
+    // Gold has no cutoff, so the next medal available from Silver is Platinum.
+    let progress = get_achievement(Some(50.0), Some(2)).progress(&get_cutoffs()).unwrap();
+    assert_eq!(progress.next, AchievementRank::Platinum);
+    assert_eq!(progress.remaining, 30.0);
+    assert_eq!(progress.progress, 0.25);
+
+    let progress = get_achievement(Some(10.0), None).progress(&get_cutoffs()).unwrap();
+    assert_eq!(progress.next, AchievementRank::Bronze);
+    assert_eq!(progress.progress, 0.5);
+
+    assert!(get_achievement(Some(120.0), Some(5)).progress(&get_cutoffs()).is_none());
+    assert!(get_achievement(None, None).progress(&get_cutoffs()).is_none());
+}
+
+#[test]
+fn ar_counts() {
+    let counts: UserArCounts = serde_json::from_value(json!({ "1": 10, "2": 5, "5": 1, "100": 3, "t100": 2, "t3": 1 })).unwrap();
+
+    assert_eq!(counts.medal(AchievementRank::Bronze), 10);
+    assert_eq!(counts.medal(AchievementRank::Gold), 0);
+    assert_eq!(counts.top(3), 1);
+    assert_eq!(counts.total_medals(), 19);
+    assert_eq!(counts.top_breakdown()[0], (100, 2));
+}
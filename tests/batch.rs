@@ -0,0 +1,85 @@
+#![cfg(feature = "in_memory_cache")]
+
+mod common;
+
+use std::time::Duration;
+
+use serde_json::json;
+use tetrio_api::http::{
+    batch::{dedupe_users, BatchProgress},
+    cached_client::CachedClient,
+    caches::moka::MokaCache,
+    rate_limiters::local::LocalRateLimiter,
+};
+
+use common::{failure, hit, success, FakeClient};
+
+fn users_client() -> FakeClient {
+    FakeClient::new(|request| match request.path.as_str() {
+        "/api/users/broken" => Err(std::io::Error::other("connection reset")),
+        "/api/users/ghost" | "/api/users/ghost/summaries" => Ok(failure("No such user!")),
+        path => Ok(success(json!({ "path": path }))),
+    })
+}
+
+fn client(fake: &FakeClient) -> CachedClient<FakeClient, MokaCache> {
+    CachedClient::with_rate_limiter(fake.clone(), MokaCache::default(), LocalRateLimiter::new(Duration::from_millis(20)))
+}
+
+#[test]
+fn users_are_deduplicated() {
+    assert_eq!(dedupe_users(["osk", "OSK", " zudo ", "", "osk"]), ["osk", "zudo"]);
+}
+
+#[tokio::test]
+async fn one_failure_does_not_fail_the_batch() {
+    let fake = users_client();
+    let client = client(&fake);
+
+    client.cache_tetrio_api_result_if_not_present::<serde_json::Value>("users/zudo", None, &hit(json!({ "path": "cached" }))).await.unwrap();
+
+    let mut reports: Vec<BatchProgress> = vec![];
+    let results = client
+        .fetch_users::<serde_json::Value>(["osk", "Zudo", "broken", "ghost", "OSK"], |user| format!("users/{user}"), |progress| reports.push(progress))
+        .await;
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(results["osk"].as_ref().unwrap().data, Some(json!({ "path": "/api/users/osk" })));
+    assert_eq!(results["zudo"].as_ref().unwrap().data, Some(json!({ "path": "cached" })));
+    assert!(results["broken"].is_err());
+    assert!(!results["ghost"].as_ref().unwrap().is_success());
+
+    assert_eq!(fake.sorted_paths(), ["/api/users/broken", "/api/users/ghost", "/api/users/osk"]);
+
+    assert_eq!(reports.len(), 4);
+    assert_eq!(reports.iter().map(|progress| progress.done).collect::<Vec<_>>(), [1, 2, 3, 4]);
+    assert_eq!(reports[0].cached, 1);
+    assert_eq!(reports[0].eta, None);
+    assert!(reports[1].eta.is_some());
+
+    let last = reports.last().unwrap();
+    assert!(last.is_done());
+    assert_eq!((last.total, last.cached, last.failed), (4, 1, 2));
+    assert_eq!(last.eta, Some(Duration::ZERO));
+    assert_eq!(last.fraction(), 1.0);
+}
+
+#[tokio::test]
+async fn typed_batches_report_per_user_results() {
+    let fake = users_client();
+    let client = client(&fake);
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let results = client.fetch_users_info(["ghost", "broken"], |progress| sender.send(progress).unwrap()).await;
+
+    assert_eq!(results["ghost"].as_ref().unwrap().error.as_ref().unwrap().msg, "No such user!");
+    assert!(results["broken"].is_err());
+    assert_eq!(receiver.try_iter().count(), 2);
+
+    let summaries = client.fetch_users_summaries(["ghost"], |_| {}).await;
+    let ghost = summaries["ghost"].as_ref().unwrap();
+    assert!(!ghost.is_success());
+    assert!(ghost.data.is_none());
+    assert_eq!(ghost.error.as_ref().unwrap().msg, "No such user!");
+    assert_eq!(fake.paths().last().unwrap(), "/api/users/ghost/summaries");
+}
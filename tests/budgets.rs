@@ -0,0 +1,88 @@
+#![cfg(feature = "in_memory_cache")]
+
+mod common;
+
+use std::time::Duration;
+
+use serde_json::json;
+use tetrio_api::http::{
+    budgets::{Budget, OnExhausted, TenantBudgets},
+    cached_client::CachedClient,
+    caches::moka::MokaCache,
+    error::Error,
+    rate_limiters::local::LocalRateLimiter,
+};
+
+use common::FakeClient;
+
+fn client(budgets: TenantBudgets, fake: &FakeClient) -> CachedClient<FakeClient, MokaCache> {
+    CachedClient::with_rate_limiter(fake.clone(), MokaCache::default(), LocalRateLimiter::new(Duration::ZERO))
+        .with_budgets(budgets)
+}
+
+#[tokio::test]
+async fn budgets_are_per_tenant_and_cache_hits_are_free() {
+    let fake = FakeClient::always(json!(1));
+    let client = client(TenantBudgets::new(Budget::per_minute(2)), &fake);
+    let spammer = client.for_tenant("guild-1");
+    let other = client.for_tenant("guild-2");
+
+    assert_eq!(spammer.tenant(), Some("guild-1"));
+    assert_eq!(client.remaining_budget(), None);
+    assert_eq!(spammer.remaining_budget().unwrap().remaining, 2);
+
+    spammer.make_tetrio_api_request::<i64>("users/a", None).await.unwrap();
+    spammer.make_tetrio_api_request::<i64>("users/a", None).await.unwrap();
+    assert_eq!(spammer.remaining_budget().unwrap().remaining, 1);
+
+    spammer.make_tetrio_api_request::<i64>("users/b", None).await.unwrap();
+    let status = spammer.remaining_budget().unwrap();
+    assert!(status.is_exhausted());
+    assert!(status.resets_in <= Duration::from_secs(60));
+
+    match spammer.make_tetrio_api_request::<i64>("users/c", None).await {
+        Err(Error::BudgetExhausted(status)) => assert_eq!(status.limit, 2),
+        other => panic!("expected the budget to be exhausted, got {other:?}"),
+    }
+
+    // Cached responses are still served, and the other tenants and untracked requests are not affected.
+    assert_eq!(spammer.make_tetrio_api_request::<i64>("users/b", None).await.unwrap().data, Some(1));
+    other.make_tetrio_api_request::<i64>("users/c", None).await.unwrap();
+    client.make_tetrio_api_request::<i64>("users/d", None).await.unwrap();
+
+    assert_eq!(fake.request_count(), 4);
+    assert_eq!(other.remaining_budget().unwrap().remaining, 1);
+}
+
+#[tokio::test]
+async fn exhausted_budgets_can_degrade_to_cache_only() {
+    let fake = FakeClient::always(json!(1));
+    let budgets = TenantBudgets::new(Budget::per_minute(1))
+        .with_budget("premium", Budget::per_minute(5))
+        .when_exhausted(OnExhausted::CacheOnly);
+    let client = client(budgets, &fake);
+    let tenant = client.for_tenant("guild");
+
+    tenant.make_tetrio_api_request::<i64>("users/a", None).await.unwrap();
+    let packet = tenant.make_tetrio_api_request::<i64>("users/b", None).await.unwrap();
+
+    assert!(!packet.is_success());
+    assert!(packet.error.unwrap().msg.contains("budget exhausted"));
+    assert_eq!(fake.request_count(), 1);
+    assert_eq!(client.for_tenant("premium").remaining_budget().unwrap().remaining, 5);
+}
+
+#[test]
+fn budgets_refill_after_their_window() {
+    let budgets = TenantBudgets::new(Budget::new(1, Duration::from_millis(50)));
+
+    assert_eq!(budgets.try_spend("guild").unwrap().remaining, 0);
+    assert!(budgets.try_spend("guild").is_err());
+
+    std::thread::sleep(Duration::from_millis(60));
+    assert_eq!(budgets.remaining("guild").remaining, 1);
+    assert!(budgets.try_spend("guild").is_ok());
+
+    budgets.reset("guild");
+    assert_eq!(budgets.remaining("guild").remaining, 1);
+}
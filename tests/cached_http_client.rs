@@ -1,5 +1,5 @@
 
- use tetrio_api::{http::{clients::reqwest_client::InMemoryReqwestClient, parameters::{leaderboard_query::LeaderboardType, value_bound_query::{Prisecter, ValueBoundQuery}}}, models::packet::Packet};
+ use tetrio_api::{http::{clients::reqwest_client::InMemoryReqwestClient, parameters::{leaderboard_query::LeaderboardType, personal_user_records::ScoreFlowMode, value_bound_query::{Prisecter, ValueBoundQuery}}}, models::packet::Packet};
 
  use tokio::runtime::Runtime;
 
@@ -416,8 +416,8 @@
      async fn fetch_scoreflow() {
 
          let client = get_client();
-         test_ok_success_is_some(client.fetch_scoreflow("taka", "40l").await);
-         test_ok_success_is_some(client.fetch_scoreflow("taka", "40l").await);
+         test_ok_success_is_some(client.fetch_scoreflow("taka", ScoreFlowMode::Sprint).await);
+         test_ok_success_is_some(client.fetch_scoreflow("taka", ScoreFlowMode::Sprint).await);
      }
      get_tokio_runtime().block_on(fetch_scoreflow())
  }
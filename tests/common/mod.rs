@@ -0,0 +1,218 @@
+//! Fixtures and a fake http client shared by the integration tests.
+#![allow(dead_code)]
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use bytes::Bytes;
+use http::Request;
+use serde_json::{json, Value};
+use tetrio_api::http::clients::http_client::HttpClient;
+
+/// Overrides the fields of `base` with the ones of `overrides`, merging nested objects.
+pub fn merge(mut base: Value, overrides: Value) -> Value {
+    match (&mut base, overrides) {
+        (Value::Object(base), Value::Object(overrides)) => {
+            for (key, value) in overrides {
+                let merged = match base.remove(&key) {
+                    Some(previous) => merge(previous, value),
+                    None => value,
+                };
+                base.insert(key, merged);
+            }
+        }
+        (base, overrides) => *base = overrides,
+    }
+    base
+}
+
+pub fn parse<T: serde::de::DeserializeOwned>(value: Value) -> T {
+    serde_json::from_value(value).unwrap()
+}
+
+/// A successful packet, cached by the API for a minute.
+pub fn success(data: Value) -> Value {
+    success_for(data, Duration::from_secs(60))
+}
+
+/// A successful packet, cached by the API for `ttl`.
+pub fn success_for(data: Value, ttl: Duration) -> Value {
+    let cached_until = (SystemTime::now().duration_since(UNIX_EPOCH).unwrap() + ttl).as_millis();
+    json!({ "success": true, "cache": { "status": "miss", "cached_at": 0, "cached_until": cached_until }, "data": data })
+}
+
+/// A packet to put in a cache, which doesn't expire during the tests.
+pub fn hit(data: Value) -> String {
+    json!({ "success": true, "cache": { "status": "hit", "cached_at": 0, "cached_until": u64::MAX / 2 }, "data": data }).to_string()
+}
+
+pub fn failure(msg: &str) -> Value {
+    json!({ "success": false, "error": { "msg": msg } })
+}
+
+/// The info of a user without any badge, achievement or stat, with `overrides` applied.
+pub fn user_info(username: &str, overrides: Value) -> Value {
+    merge(
+        json!({
+            "_id": "id", "username": username, "role": "user", "ts": null, "botmaster": null, "badges": [],
+            "xp": 0.0, "gamesplayed": 0, "gameswon": 0, "gametime": 0.0, "country": null, "badstanding": null,
+            "supporter": null, "supporter_tier": 0, "avatar_revision": null, "banner_revision": null, "bio": null,
+            "connections": {}, "friend_count": null, "distinguishment": null, "achievements": [],
+            "ar": 0, "ar_counts": {}
+        }),
+        overrides,
+    )
+}
+
+/// A TETRA LEAGUE leaderboard entry of 100 games, with `overrides` applied.
+pub fn leaderboard_user(username: &str, rank: Option<&str>, tr: f64, overrides: Value) -> Value {
+    merge(
+        json!({
+            "_id": username, "username": username, "role": "user", "ts": null, "xp": 0.0, "country": null, "supporter": null,
+            "league": { "gamesplayed": 100, "gameswon": 50, "tr": tr, "gxe": 99.0, "rank": rank, "bestrank": rank, "glicko": 3000.0, "rd": 60.0,
+                        "apm": null, "pps": 3.0, "vs": 300.0, "decaying": false },
+            "gamesplayed": 100, "gameswon": 50, "gametime": 0.0, "friend_count": null, "ar": 0, "ar_counts": {},
+            "p": { "pri": 0.0, "sec": 0.0, "ter": 0.0 }
+        }),
+        overrides,
+    )
+}
+
+/// The line clears of a record, all zero unless overridden.
+pub fn clears(overrides: Value) -> Value {
+    merge(
+        json!({
+            "singles": 0, "doubles": 0, "triples": 0, "quads": 0, "realtspins": 0, "minitspins": 0,
+            "minitspinsingles": 0, "tspinsingles": 0, "minitspindoubles": 0, "tspindoubles": 0,
+            "tspintriples": 0, "tspinquads": 0, "allclear": 0
+        }),
+        overrides,
+    )
+}
+
+/// The stats of a 40 LINES run of 100 pieces in 25 seconds, with `overrides` applied.
+pub fn sprint_stats(overrides: Value) -> Value {
+    merge(
+        json!({
+            "lines": 40, "level_lines": 0, "level_lines_needed": 1, "inputs": 300, "holds": 10,
+            "score": 0, "level": 1, "combo": 0, "topcombo": 0, "btb": 0, "topbtb": 0, "tspins": 0,
+            "piecesplaced": 100, "clears": clears(json!({ "quads": 10 })),
+            "garbage": { "sent": 0, "received": 0 },
+            "finesse": { "combo": 0, "faults": 0, "perfectpieces": 100 },
+            "finaltime": 25000.0
+        }),
+        overrides,
+    )
+}
+
+/// A record of `gamemode` with the given stats, not attached to a user.
+pub fn record(gamemode: &str, stats: Value) -> Value {
+    json!({
+        "_id": "record", "replayid": "replay", "stub": false, "gamemode": gamemode, "pb": true, "oncepb": true,
+        "ts": "2024-08-01T12:00:00.000Z", "revolution": null, "user": null, "otherusers": [], "leaderboards": [],
+        "results": { "aggregatestats": { "apm": 0.0, "pps": 0.0, "vsscore": 0.0 }, "stats": stats, "gameoverreason": "winner" },
+        "extras": {}, "disputed": false, "p": { "pri": 0.0, "sec": 0.0, "ter": 0.0 }
+    })
+}
+
+/// A 40 LINES record, its replay ID being `replay-{id}`.
+pub fn sprint_record(id: &str) -> Value {
+    merge(record("40l", sprint_stats(json!({}))), json!({ "_id": id, "replayid": format!("replay-{id}") }))
+}
+
+/// A request received by a [`FakeClient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FakeRequest {
+    pub path: String,
+    /// The path with the query string.
+    pub uri: String,
+    pub session_id: Option<String>,
+}
+
+type Handler = dyn Fn(&FakeRequest) -> Result<Value, std::io::Error> + Send + Sync;
+
+/// Answers requests with the packets returned by its handler and records every request.
+///
+/// Clones share the same handler and records, so a clone can be kept to inspect the requests made by a client.
+#[derive(Clone)]
+pub struct FakeClient {
+    handler: Arc<Handler>,
+    requests: Arc<Mutex<Vec<FakeRequest>>>,
+    delay: Duration,
+    in_flight: Arc<AtomicUsize>,
+    max_in_flight: Arc<AtomicUsize>,
+}
+
+impl FakeClient {
+    pub fn new(handler: impl Fn(&FakeRequest) -> Result<Value, std::io::Error> + Send + Sync + 'static) -> Self {
+        Self {
+            handler: Arc::new(handler),
+            requests: Arc::default(),
+            delay: Duration::ZERO,
+            in_flight: Arc::default(),
+            max_in_flight: Arc::default(),
+        }
+    }
+
+    /// Answers every request with the same data.
+    pub fn always(data: Value) -> Self {
+        Self::new(move |_| Ok(success(data.clone())))
+    }
+
+    /// Waits `delay` before answering each request.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn requests(&self) -> Vec<FakeRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// The paths requested, in the order the requests were received.
+    pub fn paths(&self) -> Vec<String> {
+        self.requests().into_iter().map(|request| request.path).collect()
+    }
+
+    /// The paths requested, sorted, for requests made concurrently.
+    pub fn sorted_paths(&self) -> Vec<String> {
+        let mut paths = self.paths();
+        paths.sort();
+        paths
+    }
+
+    pub fn request_count(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+
+    /// The most requests which were being answered at the same time.
+    pub fn max_in_flight(&self) -> usize {
+        self.max_in_flight.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for FakeClient {
+    type HttpError = std::io::Error;
+
+    async fn execute(&self, request: Request<Vec<u8>>) -> Result<Bytes, Self::HttpError> {
+        let request = FakeRequest {
+            path: request.uri().path().to_string(),
+            uri: request.uri().path_and_query().unwrap().to_string(),
+            session_id: request.headers().get("X-SESSION-ID").map(|value| value.to_str().unwrap().to_string()),
+        };
+        self.requests.lock().unwrap().push(request.clone());
+
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+        if !self.delay.is_zero() {
+            tokio::time::sleep(self.delay).await;
+        }
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        (self.handler)(&request).map(|packet| Bytes::from(packet.to_string()))
+    }
+}
@@ -0,0 +1,188 @@
+#![cfg(feature = "embeds")]
+
+mod common;
+
+use serde_json::json;
+use tetrio_api::analysis::{league_match::MatchSummary, zenith::{ZenithFloor, ZenithRun}};
+use tetrio_api::embeds::{self, Embed, FIELD_COUNT_LIMIT, FIELD_VALUE_LIMIT, TITLE_LIMIT};
+use tetrio_api::models::users::{
+    summaries::AllSummaries, user_info::UserInfo, user_leaderboard::LeaderboardUser, user_records::zenith_record::ZenithMod,
+};
+use tetrio_api::urls::Urls;
+
+use common::{parse, user_info};
+
+fn info() -> UserInfo {
+    parse(user_info("osk", json!({
+        "_id": "5e32fc85ab319c2ab1beb07c", "role": "sysop", "ts": "2020-01-30T15:58:29.000Z",
+        "badges": [{ "id": "early", "label": "early", "ts": null }], "xp": 1234567.8, "gamesplayed": 200, "gameswon": 150,
+        "gametime": 5430.0, "country": "JP", "avatar_revision": 42, "bio": "", "ar": 1500
+    })))
+}
+
+fn summaries(league: serde_json::Value) -> AllSummaries {
+    let zenith = json!({ "record": null, "rank": -1, "rank_local": -1, "best": { "record": null, "rank": -1 } });
+
+    serde_json::from_value(json!({
+        "40l": { "record": null, "rank": -1, "rank_local": -1 },
+        "blitz": { "record": null, "rank": -1, "rank_local": -1 },
+        "zenith": zenith,
+        "zenithex": zenith,
+        "league": league,
+        "zen": { "level": 1234, "score": 1000.0 },
+        "achievements": [],
+    }))
+    .unwrap()
+}
+
+fn leaderboard_user(username: &str, rank: &str, tr: f64) -> LeaderboardUser {
+    parse(common::leaderboard_user(username, Some(rank), tr, json!({ "country": "fr", "league": { "apm": 150.0 } })))
+}
+
+fn field<'a>(embed: &'a Embed, name: &str) -> Option<&'a str> {
+    embed.fields.iter().find(|field| field.name == name).map(|field| field.value.as_str())
+}
+
+fn league_embed_of(league: serde_json::Value) -> Embed {
+    embeds::league_embed(&info(), &summaries(league).league, &Urls::default())
+}
+
+#[test]
+fn users_are_rendered() {
+    let urls = Urls::default();
+    let embed = embeds::user_embed(&info(), &urls);
+
+    assert_eq!(embed.title.as_deref(), Some("osk"));
+    assert_eq!(embed.thumbnail.as_ref().unwrap().url, urls.avatar("5e32fc85ab319c2ab1beb07c", Some(42)));
+    assert_eq!(embed.timestamp.as_deref(), Some("2020-01-30T15:58:29.000Z"));
+    assert_eq!(embed.footer.as_ref().unwrap().text, "Joined");
+    assert_eq!(field(&embed, "Country"), Some("🇯🇵 JP"));
+    assert_eq!(field(&embed, "Role"), Some("SYSOP"));
+    assert_eq!(field(&embed, "XP"), Some("1,234,567"));
+    assert_eq!(field(&embed, "Games"), Some("150 / 200 won (75.0%)"));
+    assert_eq!(field(&embed, "Play time"), Some("1h 30m"));
+
+    // Empty bios are left out, and so are the other empty parts of the JSON.
+    let json = embed.to_json();
+    assert!(json.get("description").is_none() && json.get("author").is_none());
+    assert_eq!(json["fields"][0], json!({ "name": "Country", "value": "🇯🇵 JP", "inline": true }));
+
+    // Anonymous users have no join date, and so no footer.
+    let anonymous = embeds::user_embed(&parse(user_info("anon", json!({}))), &urls);
+    assert!(anonymous.timestamp.is_none() && anonymous.footer.is_none());
+}
+
+#[test]
+fn league_summaries_use_the_rank_colour() {
+    let urls = Urls::default();
+    let league = json!({ "gamesplayed": 100, "gameswon": 60, "tr": 23456.789, "gxe": 91.2, "glicko": 2200.0, "rd": 61.5,
+                         "rank": "s+", "bestrank": "ss", "standing": 1234, "standing_local": 12, "apm": 120.5, "pps": 2.45, "vs": 260.0, "decaying": true });
+    let summaries = summaries(league);
+    let embed = embeds::league_embed(&info(), &summaries.league, &urls);
+
+    assert_eq!(embed.color, Some(0xD8AF0E));
+    assert_eq!(embed.author.as_ref().unwrap().name, "osk");
+    assert_eq!(field(&embed, "Rank"), Some("S+ (best SS)"));
+    assert_eq!(field(&embed, "TR"), Some("23456.79"));
+    assert_eq!(field(&embed, "Glicko"), Some("2200 ± 62"));
+    assert_eq!(field(&embed, "Standing"), Some("#1,234 (#12 local)"));
+    assert_eq!(embed.footer.unwrap().text, "Rating deviation is rising");
+
+    let unranked = league_embed_of(json!({ "gamesplayed": 3, "gameswon": 1, "tr": -1.0, "gxe": -1.0, "rank": "z", "standing": -1 }));
+    assert_eq!(unranked.description.as_deref(), Some("Unranked"));
+    assert_eq!(unranked.color, None);
+    assert!(field(&unranked, "TR").is_none());
+}
+
+#[test]
+fn missing_records_are_dashes() {
+    let summaries = summaries(json!({ "gamesplayed": 0, "gameswon": 0, "tr": -1.0, "rank": "z" }));
+    let embed = embeds::summaries_embed(&info(), &summaries, &Urls::default());
+
+    assert_eq!(field(&embed, "40 LINES"), Some("—"));
+    assert_eq!(field(&embed, "TETRA LEAGUE"), Some("Unranked"));
+    assert_eq!(field(&embed, "ZEN"), Some("Level 1,234"));
+}
+
+#[test]
+fn matches_are_recapped() {
+    let player = |id: &str, wins: i64, before: f64, after: f64, rank: &str| {
+        json!({ "id": id, "username": id, "active": true, "wins": wins, "apm": 100.0, "pps": 2.0, "vs": 200.0, "rounds": [],
+                "before": { "tr": before, "glicko": 2000.0, "rd": 60.0, "rank": "a", "placement": null },
+                "after": { "tr": after, "glicko": 2000.0, "rd": 60.0, "rank": rank, "placement": null } })
+    };
+    let round = |index: usize, winner: &str| json!({ "index": index, "winner": winner, "duration": 61500, "players": [] });
+
+    let summary: MatchSummary = serde_json::from_value(json!({
+        "id": "record", "replayid": "replay", "ts": "2024-08-01T12:00:00.000Z", "result": "victory",
+        "players": [player("osk", 2, 20000.0, 20050.5, "a+"), player("zudo", 1, 20100.0, 20060.0, "a")],
+        "rounds": [round(0, "osk"), round(1, "zudo"), round(2, "osk")],
+    }))
+    .unwrap();
+    let embed = embeds::match_embed(&summary, &Urls::default());
+
+    assert_eq!(embed.title.as_deref(), Some("osk vs zudo"));
+    assert_eq!(embed.description.as_deref(), Some("**2–1** · osk wins"));
+    assert_eq!(embed.color, Some(0x1FA834));
+    assert_eq!(field(&embed, "osk (winner)"), Some("APM 100.00\nPPS 2.00\nVS 200.00\nTR 20000.00 → 20050.50 (+50.50)\nRank A → A+"));
+    assert_eq!(field(&embed, "zudo"), Some("APM 100.00\nPPS 2.00\nVS 200.00\nTR 20100.00 → 20060.00 (-40.00)"));
+    assert_eq!(field(&embed, "Rounds"), Some("1. osk (01:01.500)\n2. zudo (01:01.500)\n3. osk (01:01.500)"));
+}
+
+#[test]
+fn zenith_runs_are_rendered() {
+    let run = ZenithRun {
+        id: "run".to_string(),
+        expert: true,
+        altitude: 1234.56,
+        floor: ZenithFloor::from_altitude(1234.56),
+        finaltime: 300_000.0,
+        splits: vec![],
+        mods: vec![ZenithMod::Expert, ZenithMod::NoHold],
+        speedrun: false,
+        speedrun_seen: false,
+        revives: 0,
+        revives_total: 0,
+        peak_rank: 12.3,
+    };
+    let embed = embeds::zenith_embed(&info(), &run, &Urls::default());
+
+    assert_eq!(embed.title.as_deref(), Some("EXPERT QUICK PLAY"));
+    assert_eq!(embed.description.as_deref(), Some("**1234.6 m** · Floor 8 — The Core"));
+    assert_eq!(field(&embed, "Time"), Some("05:00.000"));
+    assert_eq!(field(&embed, "Climb speed"), Some("4.12 m/s"));
+    assert_eq!(field(&embed, "Mods"), Some("The Emperor, Temperance"));
+    assert!(field(&embed, "Revives").is_none());
+}
+
+#[test]
+fn leaderboard_pages_are_numbered() {
+    let entries = [leaderboard_user("first", "x+", 25000.0), leaderboard_user("second", "x", 24900.5)];
+    let embed = embeds::leaderboard_embed("TETRA LEAGUE", &entries, 51);
+
+    assert_eq!(embed.description.as_deref(), Some("`#51` 🇫🇷 **first** · X+ · 25000.00 TR\n`#52` 🇫🇷 **second** · X · 24900.50 TR"));
+    assert_eq!(embed.footer.unwrap().text, "#51 – #52");
+    assert_eq!(embed.color, Some(0x643C8D));
+
+    // The footer only counts the entries which fit in the description.
+    let entries = (0..200).map(|index| leaderboard_user(&format!("player{index}"), "x", 24000.0)).collect::<Vec<_>>();
+    let embed = embeds::leaderboard_embed("TETRA LEAGUE", &entries, 1);
+    let shown = embed.description.as_deref().unwrap().lines().count();
+    assert!(shown < entries.len());
+    assert_eq!(embed.footer.unwrap().text, format!("#1 – #{shown}"));
+}
+
+#[test]
+fn discord_limits_are_respected() {
+    let mut embed = Embed::new().title("a".repeat(300));
+    for index in 0..30 {
+        embed = embed.field(index.to_string(), "b".repeat(2000), true);
+    }
+
+    assert_eq!(embed.title.unwrap().chars().count(), TITLE_LIMIT);
+    assert_eq!(embed.fields.len(), FIELD_COUNT_LIMIT);
+    assert!(embed.fields.iter().all(|field| field.value.chars().count() == FIELD_VALUE_LIMIT && field.value.ends_with('…')));
+
+    let empty = Embed::new().field("Empty", "", false);
+    assert_eq!(empty.fields[0].value, "—");
+}
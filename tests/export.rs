@@ -0,0 +1,130 @@
+mod common;
+
+use serde_json::json;
+use tetrio_api::export::{self, Cell, Exportable, SOLO_RECORD_COLUMNS};
+use tetrio_api::models::users::{
+    user_leaderboard::LeaderboardUser,
+    user_records::{LeagueRecord, SprintRecord},
+};
+
+use common::{clears, merge, parse, record, sprint_stats};
+
+fn sprint_record(id: &str, username: &str) -> SprintRecord {
+    let stats = sprint_stats(json!({
+        "topcombo": 3, "topbtb": 2, "tspins": 1,
+        "clears": clears(json!({ "singles": 1, "doubles": 2, "triples": 3, "quads": 7, "tspindoubles": 1, "allclear": 1 })),
+        "garbage": { "sent": 4 },
+        "finesse": { "combo": 50, "faults": 2, "perfectpieces": 98 },
+        "finaltime": 25123.5
+    }));
+
+    parse(merge(common::sprint_record(id), json!({
+        "leaderboards": ["40l_global"],
+        "user": { "id": "user-id", "username": username, "avatar_revision": null, "banner_revision": null, "country": "FR", "supporter": false },
+        "results": { "aggregatestats": { "apm": 10.0, "pps": 3.98 }, "stats": stats }
+    })))
+}
+
+fn leaderboard_user(username: &str, rank: Option<&str>, tr: f64) -> LeaderboardUser {
+    parse(common::leaderboard_user(username, rank, tr, json!({ "xp": 12.5 })))
+}
+
+fn column<'a, T: Exportable>(row: &'a [Cell], name: &str) -> &'a Cell {
+    &row[T::COLUMNS.iter().position(|column| column.name == name).unwrap()]
+}
+
+#[test]
+fn records_are_flattened() {
+    let rows = export::cells(&[sprint_record("pb", "osk")]);
+    let row = &rows[0];
+
+    assert_eq!(row.len(), SOLO_RECORD_COLUMNS.len());
+    assert_eq!(column::<SprintRecord>(row, "gamemode"), &Cell::Text("40l".to_string()));
+    assert_eq!(column::<SprintRecord>(row, "username"), &Cell::Text("osk".to_string()));
+    assert_eq!(column::<SprintRecord>(row, "finaltime"), &Cell::Float(25123.5));
+    assert_eq!(column::<SprintRecord>(row, "clears_quads"), &Cell::Int(7));
+    assert_eq!(column::<SprintRecord>(row, "clears_pentas"), &Cell::Null);
+    assert_eq!(column::<SprintRecord>(row, "garbage_sent"), &Cell::Int(4));
+    assert_eq!(column::<SprintRecord>(row, "finesse_faults"), &Cell::Int(2));
+    assert_eq!(column::<SprintRecord>(row, "zenith_altitude"), &Cell::Null);
+}
+
+#[test]
+fn csv_has_a_stable_header_and_escapes_text() {
+    let mut csv = vec![];
+    export::write_csv(&mut csv, &[leaderboard_user("osk", Some("x+"), 25000.0), leaderboard_user("a,\"b\"", None, 24000.5)]).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let lines = csv.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("id,username,role,ts,country,supporter,xp,"));
+    assert_eq!(lines[0].split(',').count(), LeaderboardUser::COLUMNS.len());
+    assert!(lines[1].starts_with("osk,osk,user,,,,12.5,100,50,0,,0,100,50,25000,99,x+,x+,3000,60,,3,300,false"));
+    assert!(lines[2].starts_with("\"a,\"\"b\"\"\",\"a,\"\"b\"\"\",user,"));
+
+    // Empty exports still have every column.
+    let mut empty = vec![];
+    export::write_csv::<LeaderboardUser>(&mut empty, &[]).unwrap();
+    assert_eq!(String::from_utf8(empty).unwrap().trim_end().split(',').count(), LeaderboardUser::COLUMNS.len());
+}
+
+#[test]
+fn ndjson_keeps_the_column_order() {
+    let mut ndjson = vec![];
+    export::write_ndjson(&mut ndjson, &[sprint_record("pb", "osk"), sprint_record("old", "osk")]).unwrap();
+    let ndjson = String::from_utf8(ndjson).unwrap();
+    let lines = ndjson.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with(r#"{"id":"pb","replayid":"replay-pb","gamemode":"40l","ts":"2024-08-01T12:00:00.000Z","pb":true,"#));
+
+    let row: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+    assert_eq!(row["clears_allclear"], json!(1));
+    assert_eq!(row["zenith_mods"], json!(null));
+    assert_eq!(row.as_object().unwrap().len(), SOLO_RECORD_COLUMNS.len());
+}
+
+#[test]
+fn league_records_have_a_row_per_player() {
+    let stats = json!({ "apm": 60.0, "pps": 2.0, "vsscore": 120.0, "garbagesent": 30.0, "garbagereceived": 20.0, "kills": 1.0,
+                        "altitude": 0.0, "rank": 0.0, "targetingfactor": 0.0, "targetinggrace": 0.0 });
+    let player = |id: &str, wins: i64| json!({ "id": id, "username": id, "active": true, "wins": wins, "stats": stats, "naturalorder": 0, "shadowedBy": [], "shadows": [] });
+    let rating = |tr: f64, rank: &str| json!({ "glicko": 1800.0, "placement": null, "rank": rank, "rd": 60.0, "tr": tr });
+
+    let mut record = record("league", json!(null));
+    record["pb"] = json!(false);
+    record["oncepb"] = json!(false);
+    record["results"] = json!({ "leaderboard": [player("a", 2), player("b", 1)], "rounds": [] });
+    record["extras"] = json!({ "league": { "a": [rating(15000.0, "a"), rating(15100.0, "a+")], "b": [rating(15200.0, "a+"), null] }, "result": "victory" });
+    let record: LeagueRecord = parse(record);
+
+    let rows = export::cells(&[record]);
+    assert_eq!(rows.len(), 2);
+    assert_eq!(column::<LeagueRecord>(&rows[0], "player_id"), &Cell::Text("a".to_string()));
+    assert_eq!(column::<LeagueRecord>(&rows[0], "rank_after"), &Cell::Text("a+".to_string()));
+    assert_eq!(column::<LeagueRecord>(&rows[1], "wins"), &Cell::Int(1));
+    assert_eq!(column::<LeagueRecord>(&rows[1], "tr_before"), &Cell::Float(15200.0));
+    assert_eq!(column::<LeagueRecord>(&rows[1], "tr_after"), &Cell::Null);
+    assert_eq!(column::<LeagueRecord>(&rows[1], "result"), &Cell::Text("victory".to_string()));
+}
+
+#[cfg(feature = "parquet_export")]
+#[test]
+fn parquet_files_can_be_read_back() {
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use parquet::record::RowAccessor;
+
+    let mut file = vec![];
+    export::write_parquet(&mut file, &[sprint_record("pb", "osk"), sprint_record("old", "zudo")]).unwrap();
+
+    let reader = SerializedFileReader::new(bytes::Bytes::from(file)).unwrap();
+    let metadata = reader.metadata().file_metadata();
+    assert_eq!(metadata.num_rows(), 2);
+
+    let names = metadata.schema_descr().columns().iter().map(|column| column.name().to_string()).collect::<Vec<_>>();
+    assert_eq!(names, SOLO_RECORD_COLUMNS.iter().map(|column| column.name).collect::<Vec<_>>());
+
+    let row = reader.get_row_iter(None).unwrap().nth(1).unwrap().unwrap();
+    assert_eq!(row.get_string(1).unwrap(), "replay-old");
+    assert_eq!(row.get_string(8).unwrap(), "zudo");
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use std::time::Duration;
+
+use serde_json::json;
+use tetrio_api::http::{
+    cached_client::CachedClient,
+    caches::noop_cache::NoopCache,
+    parameters::personal_user_records::{GameMode, ScoreFlowMode},
+    rate_limiters::local::LocalRateLimiter,
+};
+use tetrio_api::analysis::flow::{best_stretch, daily_buckets, longest_loss_streak, longest_win_streak, rolling_average, worst_stretch, Streak, DAY_MILLIS};
+use tetrio_api::models::labs::{
+    leagueflow::{LeagueFlow, LeagueFlowResult},
+    scoreflow::ScoreFlow,
+};
+
+fn get_leagueflow() -> LeagueFlow {
+    serde_json::from_value(json!({
+        "startTime": DAY_MILLIS * 100,
+        "points": [
+            [0, 1, 15000, 14000],
+            [1000, 1, 15100, -1],
+            [2000, 2, 15050, 16000],
+            [DAY_MILLIS, 1, 15200, 15000],
+            [DAY_MILLIS + 1000, 1, 15300, 15000],
+            [DAY_MILLIS + 2000, 1, 15400, 15000],
+            [DAY_MILLIS + 3000, 5, 15400, 15000],
+            [DAY_MILLIS + 4000, 4, 15000, 15000]
+        ]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn scoreflow_entries() {
+    let flow: ScoreFlow = serde_json::from_value(json!({ "startTime": 1000, "points": [[0, 1, 30000], [500, 0, 31000], [900, 1, 29000]] })).unwrap();
+    let entries = flow.entries();
+
+    assert_eq!(entries[1].ts, 1500);
+    assert!(!entries[1].pb);
+    assert_eq!(flow.personal_bests().iter().map(|entry| entry.score).collect::<Vec<_>>(), vec![30000, 29000]);
+
+    let scores = entries.iter().map(|entry| entry.score as f64).collect::<Vec<_>>();
+    assert_eq!(rolling_average(&scores, 2), vec![30500.0, 30000.0]);
+    assert!(rolling_average(&scores, 4).is_empty());
+}
+
+#[test]
+fn leagueflow_entries() {
+    let flow = get_leagueflow();
+    let entries = flow.entries();
+
+    assert_eq!(entries[1].opponent_tr, None);
+    assert_eq!(entries[2].result, LeagueFlowResult::Defeat);
+    assert_eq!(entries[7].result, LeagueFlowResult::DefeatByDisqualification);
+    assert_eq!(serde_json::to_value(&flow).unwrap()["points"][7][1], 4);
+
+    let buckets = daily_buckets(&entries);
+    assert_eq!(buckets.len(), 2);
+    assert_eq!(buckets[0].day, 100);
+    assert_eq!(buckets[1].start(), DAY_MILLIS * 101);
+    assert_eq!(buckets[1].entries.len(), 5);
+}
+
+#[test]
+fn streaks_and_stretches() {
+    let entries = get_leagueflow().entries();
+
+    assert_eq!(longest_win_streak(&entries), Some(Streak { start: 3, len: 3, won: true }));
+    // The tie ends the win streak, the disqualification starts a new losing streak.
+    assert_eq!(longest_loss_streak(&entries), Some(Streak { start: 2, len: 1, won: false }));
+
+    let best = best_stretch(&entries, 3).unwrap();
+    assert_eq!((best.start, best.end, best.tr_delta), (3, 5, 350));
+    let worst = worst_stretch(&entries, 2).unwrap();
+    assert_eq!((worst.start, worst.end, worst.tr_delta), (6, 7, -400));
+    assert!(best_stretch(&entries, 8).is_none());
+    assert!(best_stretch(&entries, 0).is_none());
+}
+
+#[tokio::test]
+async fn scoreflow_modes() {
+    let fake = common::FakeClient::always(json!({ "startTime": 1000, "points": [] }));
+    let client = CachedClient::with_rate_limiter(fake.clone(), NoopCache, LocalRateLimiter::new(Duration::ZERO));
+
+    client.fetch_scoreflow("osk", ScoreFlowMode::Sprint).await.unwrap();
+    client.fetch_scoreflow("osk", ScoreFlowMode::Blitz).await.unwrap();
+    assert_eq!(fake.paths(), ["/api/labs/scoreflow/osk/40l", "/api/labs/scoreflow/osk/blitz"]);
+
+    assert_eq!(ScoreFlowMode::try_from(GameMode::Blitz), Ok(ScoreFlowMode::Blitz));
+    assert_eq!(ScoreFlowMode::try_from(GameMode::Zenith), Err(GameMode::Zenith));
+    assert_eq!(GameMode::from(ScoreFlowMode::Sprint), GameMode::Sprint);
+}
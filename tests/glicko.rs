@@ -0,0 +1,61 @@
+use tetrio_api::math::glicko::{self, GlickoRating};
+
+fn assert_close(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() < tolerance, "{a} is not close to {b}");
+}
+
+#[test]
+fn average_player_conversions() {
+    assert_close(glicko::gxe(1500.0, 60.0), 50.0, 1e-9);
+    assert_close(glicko::tr(1500.0, 60.0), 12500.0, 1e-6);
+    assert_close(glicko::glicko_from_tr(glicko::tr(2100.0, 65.0), 65.0).unwrap(), 2100.0, 1e-6);
+    assert_eq!(glicko::glicko_from_tr(25000.0, 60.0), None);
+}
+
+#[test]
+fn win_probability_is_symmetric() {
+    let strong = GlickoRating::new(2000.0, 60.0);
+    let weak = GlickoRating::new(1700.0, 80.0);
+
+    assert!(strong.win_probability(&weak) > 0.5);
+    assert_close(strong.win_probability(&weak) + weak.win_probability(&strong), 1.0, 1e-9);
+}
+
+#[test]
+fn glickman_example() {
+    // Example from Glickman's "Example of the Glicko-2 system".
+    let player = GlickoRating::with_volatility(1500.0, 200.0, 0.06);
+    let updated = player.update(
+        &[
+            (GlickoRating::new(1400.0, 30.0), 1.0),
+            (GlickoRating::new(1550.0, 100.0), 0.0),
+            (GlickoRating::new(1700.0, 300.0), 0.0),
+        ],
+        0.5,
+    );
+
+    assert_close(updated.glicko, 1464.06, 0.01);
+    assert_close(updated.rd, 151.52, 0.01);
+    assert_close(updated.volatility, 0.05999, 0.00001);
+}
+
+#[test]
+fn simulated_games() {
+    let player = GlickoRating::new(1800.0, 60.0);
+    let opponent = GlickoRating::new(1800.0, 60.0);
+
+    assert!(player.after_game(&opponent, true).tr_delta() > 0.0);
+    assert!(player.after_game(&opponent, false).tr_delta() < 0.0);
+}
+
+#[test]
+fn decaying_rd() {
+    let player = GlickoRating::new(1800.0, 60.0);
+
+    assert!(player.projected_rd(10) > player.rd);
+    assert!(player.projected_rd(100_000) <= glicko::MAX_RD);
+
+    let periods = player.periods_until_unranked().unwrap();
+    assert!(player.projected_rd(periods) > glicko::UNRANKED_RD);
+    assert!(player.projected_rd(periods - 1) <= glicko::UNRANKED_RD);
+}
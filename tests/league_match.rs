@@ -0,0 +1,83 @@
+use serde_json::json;
+use tetrio_api::analysis::league_match::MatchSummary;
+use tetrio_api::models::users::{user_rank::UserRank, user_records::LeagueRecord};
+
+fn stats(apm: f64, pps: f64, vs: f64) -> serde_json::Value {
+    json!({
+        "apm": apm, "pps": pps, "vsscore": vs,
+        "garbagesent": 0.0, "garbagereceived": 0.0, "kills": 0.0, "altitude": 0.0,
+        "rank": 0.0, "targetingfactor": 0.0, "targetinggrace": 0.0
+    })
+}
+
+fn round(id: &str, alive: bool, lifetime: i64, apm: f64) -> serde_json::Value {
+    json!({
+        "id": id, "username": id, "active": true, "alive": alive, "lifetime": lifetime,
+        "stats": stats(apm, 2.0, apm * 2.0), "naturalorder": 0, "shadowedBy": [], "shadows": []
+    })
+}
+
+fn rating(tr: f64, rank: &str) -> serde_json::Value {
+    json!({ "glicko": 1800.0, "placement": null, "rank": rank, "rd": 60.0, "tr": tr })
+}
+
+fn get_record() -> LeagueRecord {
+    serde_json::from_value(json!({
+        "_id": "record",
+        "replayid": "replay",
+        "stub": false,
+        "gamemode": "league",
+        "pb": false,
+        "oncepb": false,
+        "ts": "2024-08-01T12:00:00.000Z",
+        "revolution": null,
+        "user": null,
+        "otherusers": [],
+        "leaderboards": [],
+        "results": {
+            "leaderboard": [
+                { "id": "a", "username": "a", "active": true, "wins": 2, "stats": stats(60.0, 2.0, 120.0), "naturalorder": 0, "shadowedBy": [], "shadows": [] },
+                { "id": "b", "username": "b", "active": true, "wins": 1, "stats": { "apm": null, "pps": null, "vsscore": null, "garbagesent": 0.0, "garbagereceived": 0.0, "kills": 0.0, "altitude": 0.0, "rank": 0.0, "targetingfactor": 0.0, "targetinggrace": 0.0 }, "naturalorder": 1, "shadowedBy": [], "shadows": [] }
+            ],
+            "rounds": [
+                [round("a", true, 30000, 60.0), round("b", false, 29000, 40.0)],
+                [round("a", false, 20000, 50.0), round("b", true, 21000, 50.0)],
+                [round("a", true, 40000, 70.0), round("b", false, 39000, 60.0)]
+            ]
+        },
+        "extras": {
+            "league": {
+                "a": [rating(15000.0, "a"), rating(15100.0, "a+")],
+                "b": [rating(15200.0, "a+"), null]
+            },
+            "result": "victory"
+        },
+        "disputed": false,
+        "p": { "pri": 0.0, "sec": 0.0, "ter": 0.0 }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn match_summary() {
+    let summary = MatchSummary::from_record(&get_record());
+
+    assert_eq!(summary.score_line(), "2–1");
+    assert_eq!(summary.winner().map(|player| player.id.as_str()), Some("a"));
+
+    let winners = summary.rounds.iter().map(|round| round.winner.as_deref()).collect::<Vec<_>>();
+    assert_eq!(winners, vec![Some("a"), Some("b"), Some("a")]);
+    assert_eq!(summary.rounds[2].duration, 40000);
+
+    let a = summary.player("a").unwrap();
+    assert_eq!(a.apm, Some(60.0));
+    assert_eq!(a.rounds.len(), 3);
+    assert_eq!(a.tr_delta(), Some(100.0));
+    assert!(a.rank_changed());
+    assert_eq!(a.after.as_ref().unwrap().rank, UserRank::APlus);
+
+    // Missing aggregate stats fall back to the round averages.
+    let b = summary.player("b").unwrap();
+    assert_eq!(b.apm, Some(50.0));
+    assert_eq!(b.tr_delta(), None);
+}
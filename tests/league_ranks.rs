@@ -0,0 +1,64 @@
+use tetrio_api::models::{
+    labs::league_ranks::{LeagueRanksData, RankZone},
+    users::user_rank::UserRank,
+};
+
+fn get_league_ranks() -> LeagueRanksData {
+    serde_json::from_value(serde_json::json!({
+        "total": 1000,
+        "x": { "pos": 10, "percentile": 0.01, "tr": 24000.0, "targettr": 24200.0, "apm": null, "pps": null, "vs": null, "count": 10 },
+        "s": { "pos": 100, "percentile": 0.1, "tr": 20000.0, "targettr": 19500.0, "apm": null, "pps": null, "vs": null, "count": 90 },
+        "a": { "pos": 500, "percentile": 0.5, "tr": 12000.0, "targettr": 12000.0, "apm": null, "pps": null, "vs": null, "count": 400 },
+        "d": { "pos": 1000, "percentile": 1.0, "tr": 2000.0, "targettr": 2100.0, "apm": null, "pps": null, "vs": null, "count": 500 }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn cutoffs_are_ordered() {
+    let ranks = get_league_ranks();
+    let cutoffs = ranks.cutoffs();
+
+    let order = cutoffs.iter().map(|cutoff| cutoff.rank.clone()).collect::<Vec<_>>();
+    assert_eq!(order, vec![UserRank::X, UserRank::S, UserRank::A, UserRank::D]);
+}
+
+#[test]
+fn standing_between_cutoffs() {
+    let ranks = get_league_ranks();
+    let standing = ranks.standing(16000.0).unwrap();
+
+    assert_eq!(standing.rank, UserRank::A);
+    assert_eq!(standing.position, 300);
+    assert!((standing.percentile - 0.3).abs() < 1e-9);
+    assert_eq!(standing.next_rank, Some(UserRank::S));
+    assert_eq!(standing.tr_to_next, Some(4000.0));
+    assert_eq!(standing.prev_rank, Some(UserRank::D));
+    assert_eq!(standing.tr_to_prev, Some(4000.0));
+    assert_eq!(standing.zone, RankZone::Neutral);
+}
+
+#[test]
+fn standing_at_the_edges() {
+    let ranks = get_league_ranks();
+
+    let top = ranks.standing(25000.0).unwrap();
+    assert_eq!(top.rank, UserRank::X);
+    assert_eq!(top.position, 1);
+    assert_eq!(top.next_rank, None);
+    assert_eq!(top.zone, RankZone::Inflation { deficit: 200.0 });
+
+    let bottom = ranks.standing(1000.0).unwrap();
+    assert_eq!(bottom.rank, UserRank::D);
+    assert_eq!(bottom.prev_rank, None);
+    assert_eq!(bottom.tr_to_prev, None);
+    assert_eq!(bottom.position, 1000);
+}
+
+#[test]
+fn zone_of_rank() {
+    let ranks = get_league_ranks();
+
+    assert_eq!(ranks.zone(&UserRank::S), Some(RankZone::Deflation { excess: 500.0 }));
+    assert_eq!(ranks.zone(&UserRank::XPlus), None);
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::StreamExt;
+use serde_json::json;
+use tetrio_api::http::{
+    cached_client::CachedClient,
+    caches::noop_cache::NoopCache,
+    news::{CursorStore, FileCursorStore, MemoryCursorStore, NewsCursor, NewsError},
+    profile::PartFailure,
+    rate_limiters::local::LocalRateLimiter,
+};
+
+use common::{failure, success, FakeClient};
+
+type Streams = Arc<Mutex<HashMap<String, Vec<serde_json::Value>>>>;
+
+fn push(streams: &Streams, stream: &str, id: &str, item_type: &str, ts: &str) {
+    let item = json!({ "_id": id, "stream": stream, "type": item_type, "data": {}, "ts": ts });
+    streams.lock().unwrap().entry(stream.to_string()).or_default().push(item);
+}
+
+fn client(streams: Streams) -> CachedClient<FakeClient, NoopCache> {
+    let fake = FakeClient::new(move |request| {
+        let stream = request.path.trim_start_matches("/api/news/");
+
+        Ok(match streams.lock().unwrap().get(stream) {
+            // The API sends the newest items first.
+            Some(news) => success(json!({ "news": news.iter().rev().collect::<Vec<_>>() })),
+            None => failure("No such stream"),
+        })
+    });
+
+    CachedClient::with_rate_limiter(fake, NoopCache, LocalRateLimiter::new(Duration::ZERO))
+}
+
+fn ids(news: &[tetrio_api::models::news::latest::LatestNews]) -> Vec<&str> {
+    news.iter().map(|item| item.id.as_str()).collect()
+}
+
+#[tokio::test]
+async fn cursors_survive_restarts() {
+    let path = std::env::temp_dir().join(format!("tetrio-api-news-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let streams = Streams::default();
+    push(&streams, "global", "1", "leaderboard", "2024-08-01T00:00:00.000Z");
+    push(&streams, "user_osk", "2", "personalbest", "2024-08-01T01:00:00.000Z");
+    let client = client(streams.clone());
+
+    let subscriber = client.news_subscriber(FileCursorStore::new(&path)).global().user("osk").user("osk").types(["personalbest", "rankup"]);
+    assert_eq!(subscriber.streams(), ["global", "user_osk"]);
+
+    // The items already there are not announced.
+    let poll = subscriber.poll().await;
+    assert!(poll.news.is_empty() && poll.failures.is_empty());
+
+    push(&streams, "global", "3", "leaderboard", "2024-08-02T00:00:00.000Z");
+    push(&streams, "user_osk", "4", "rankup", "2024-08-02T02:00:00.000Z");
+    push(&streams, "user_osk", "5", "personalbest", "2024-08-02T01:00:00.000Z");
+    assert_eq!(ids(&subscriber.poll().await.news), ["5", "4"]);
+    assert!(subscriber.poll().await.news.is_empty());
+
+    // A new subscriber using the same file starts from where the previous one stopped.
+    push(&streams, "user_osk", "6", "personalbest", "2024-08-03T00:00:00.000Z");
+    let restarted = client.news_subscriber(FileCursorStore::new(&path)).user("osk");
+    assert_eq!(ids(&restarted.poll().await.news), ["6"]);
+
+    let cursor = FileCursorStore::new(&path).load("global").await.unwrap().unwrap();
+    assert_eq!(cursor.id, "3");
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn failed_streams_do_not_stop_the_others() {
+    let streams = Streams::default();
+    push(&streams, "global", "1", "leaderboard", "2024-08-01T00:00:00.000Z");
+    push(&streams, "global", "2", "badge", "2024-08-01T00:00:00.000Z");
+    let client = client(streams.clone());
+
+    let store = MemoryCursorStore::default();
+    store.save("global", &NewsCursor { id: "1".to_string(), ts: "2024-08-01T00:00:00.000Z".parse().unwrap() }).await.unwrap();
+
+    let subscriber = client.news_subscriber(store).stream("missing").global().with_backlog().limit(10);
+    let poll = subscriber.poll().await;
+
+    assert_eq!(ids(&poll.news), ["2"]);
+    assert_eq!(poll.failures.len(), 1);
+    assert_eq!(poll.failures[0].0, "missing");
+    assert!(matches!(&poll.failures[0].1, NewsError::Fetch(PartFailure::Unsuccessful(Some(msg))) if msg == "No such stream"));
+}
+
+#[tokio::test]
+async fn subscriptions_yield_new_items() {
+    let streams = Streams::default();
+    push(&streams, "global", "1", "leaderboard", "2024-08-01T00:00:00.000Z");
+    push(&streams, "global", "2", "leaderboard", "2024-08-02T00:00:00.000Z");
+    let client = client(streams.clone());
+
+    let mut news = Box::pin(client.news_subscriber(MemoryCursorStore::default()).global().with_backlog().subscribe(Duration::from_millis(20)));
+    assert_eq!(news.next().await.unwrap().unwrap().id, "1");
+    assert_eq!(news.next().await.unwrap().unwrap().id, "2");
+
+    push(&streams, "global", "3", "leaderboard", "2024-08-03T00:00:00.000Z");
+    let item = tokio::time::timeout(Duration::from_secs(1), news.next()).await.unwrap().unwrap().unwrap();
+    assert_eq!(item.id, "3");
+}
+
+#[tokio::test]
+async fn subscriptions_yield_failures_first() {
+    let streams = Streams::default();
+    push(&streams, "global", "1", "leaderboard", "2024-08-01T00:00:00.000Z");
+    push(&streams, "global", "2", "leaderboard", "2024-08-02T00:00:00.000Z");
+    let client = client(streams.clone());
+
+    let mut news = Box::pin(client.news_subscriber(MemoryCursorStore::default()).global().stream("missing").with_backlog().subscribe(Duration::from_millis(20)));
+    assert!(news.next().await.unwrap().is_err());
+    assert_eq!(news.next().await.unwrap().unwrap().id, "1");
+    assert_eq!(news.next().await.unwrap().unwrap().id, "2");
+}
@@ -0,0 +1,29 @@
+mod common;
+
+use std::time::Duration;
+
+use serde_json::json;
+use tetrio_api::http::{cached_client::CachedClient, caches::noop_cache::NoopCache};
+use tokio::time::Instant;
+
+use common::FakeClient;
+
+// The clock is paused, so the timings below are exact instead of depending on the machine's load.
+#[tokio::test(start_paused = true)]
+async fn requests_overlap_while_downloading() {
+    let fake = FakeClient::always(json!(1)).with_delay(Duration::from_millis(1500));
+    let client = CachedClient::new(fake.clone(), NoopCache);
+
+    let start = Instant::now();
+    let results = futures::future::join_all(
+        ["a", "b", "c"].map(|route| client.make_tetrio_api_request::<i64>(route, None)),
+    )
+    .await;
+
+    assert!(results.iter().all(|result| matches!(result, Ok(packet) if packet.data == Some(1))));
+    // Request starts are still spaced by the rate limit (one per second), but the responses overlap:
+    // serialized, the three requests would take at least 4.5 seconds.
+    assert!(fake.max_in_flight() >= 2);
+    assert!(start.elapsed() >= Duration::from_millis(3500));
+    assert!(start.elapsed() < Duration::from_millis(4500));
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::json;
+use tetrio_api::http::{
+    cached_client::CachedClient,
+    caches::noop_cache::NoopCache,
+    rate_limiters::{
+        local::LocalRateLimiter,
+        priority::{Priority, PriorityQueue},
+        rate_limiter::RateLimiter,
+    },
+};
+
+use common::FakeClient;
+
+#[tokio::test(start_paused = true)]
+async fn interactive_requests_go_first() {
+    let fake = FakeClient::always(json!(1));
+    let limiter = Arc::new(LocalRateLimiter::new(Duration::from_millis(100)));
+    let client = CachedClient::with_rate_limiter(fake.clone(), NoopCache, limiter.clone());
+
+    // Uses the first slot so that every request below has to wait.
+    limiter.acquire().await;
+
+    let mut tasks = vec![];
+    for (route, priority) in [("first", Priority::Normal), ("crawl-1", Priority::Background), ("crawl-2", Priority::Background), ("command", Priority::Interactive)] {
+        let handle = client.with_priority(priority);
+        assert_eq!(handle.priority(), priority);
+
+        tasks.push(tokio::spawn(async move {
+            handle.make_tetrio_api_request::<i64>(route, None).await.unwrap();
+        }));
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    assert_eq!(client.queued_requests(), 3);
+    for task in tasks {
+        task.await.unwrap();
+    }
+
+    assert_eq!(fake.paths(), ["/api/first", "/api/command", "/api/crawl-1", "/api/crawl-2"]);
+    assert_eq!(client.queued_requests(), 0);
+}
+
+/// Lets a normal request hold the turn for 10 seconds while a background request, then a newer normal one, wait for it.
+async fn serve_order(aging: Duration) -> Vec<&'static str> {
+    let order = Arc::new(Mutex::new(vec![]));
+    let limiter = Arc::new(LocalRateLimiter::new(Duration::from_secs(10)));
+    let queue = Arc::new(PriorityQueue::new(aging));
+
+    limiter.acquire().await;
+
+    let mut tasks = vec![];
+    for (name, priority) in [("first", Priority::Normal), ("background", Priority::Background), ("normal", Priority::Normal)] {
+        let (order, limiter, queue) = (order.clone(), limiter.clone(), queue.clone());
+
+        tasks.push(tokio::spawn(async move {
+            queue.acquire(priority, limiter.as_ref()).await;
+            order.lock().unwrap().push(name);
+        }));
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+
+    assert_eq!(queue.waiting(), 2);
+    for task in tasks {
+        task.await.unwrap();
+    }
+
+    let order = order.lock().unwrap().clone();
+    order
+}
+
+#[tokio::test(start_paused = true)]
+async fn waiting_requests_get_promoted() {
+    // By the time the turn is passed on, both waiting requests have been promoted to the highest priority, and the background one is older.
+    assert_eq!(serve_order(Duration::from_secs(3)).await, ["first", "background", "normal"]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn requests_keep_their_priority_until_they_age() {
+    assert_eq!(serve_order(Duration::from_secs(3600)).await, ["first", "normal", "background"]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn cancelled_requests_give_up_their_turn() {
+    let limiter = Arc::new(LocalRateLimiter::new(Duration::from_millis(50)));
+    let queue = Arc::new(PriorityQueue::default());
+
+    limiter.acquire().await;
+
+    let first = {
+        let (limiter, queue) = (limiter.clone(), queue.clone());
+        tokio::spawn(async move { queue.acquire(Priority::Normal, limiter.as_ref()).await })
+    };
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    let cancelled = {
+        let (limiter, queue) = (limiter.clone(), queue.clone());
+        tokio::spawn(async move { queue.acquire(Priority::Interactive, limiter.as_ref()).await })
+    };
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    assert_eq!(queue.waiting(), 1);
+
+    cancelled.abort();
+    let _ = cancelled.await;
+    assert_eq!(queue.waiting(), 0);
+
+    first.await.unwrap();
+    tokio::time::timeout(Duration::from_secs(1), queue.acquire(Priority::Background, limiter.as_ref()))
+        .await
+        .expect("the queue should not stay busy");
+}
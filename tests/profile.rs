@@ -0,0 +1,65 @@
+mod common;
+
+use std::time::Duration;
+
+use serde_json::json;
+use tetrio_api::http::{
+    cached_client::CachedClient,
+    caches::noop_cache::NoopCache,
+    profile::{PartFailure, ProfileOptions, ProfilePart},
+    rate_limiters::local::LocalRateLimiter,
+};
+
+use common::{failure, success, user_info, FakeClient};
+
+fn profile_client() -> FakeClient {
+    FakeClient::new(|request| match request.path.as_str() {
+        "/api/users/osk" => Ok(success(user_info("osk", json!({})))),
+        "/api/users/osk/records/league/recent" => Ok(success(json!({ "entries": [] }))),
+        "/api/labs/leagueflow/osk" => Err(std::io::Error::other("connection reset")),
+        _ => Ok(failure("Not found")),
+    })
+}
+
+#[tokio::test]
+async fn profile_parts_fail_independently() {
+    let fake = profile_client();
+    let client = CachedClient::with_rate_limiter(fake.clone(), NoopCache, LocalRateLimiter::new(Duration::ZERO));
+
+    let profile = client.fetch_profile("osk", ProfileOptions { league_records: Some(5), leagueflow: true }).await;
+
+    assert_eq!(profile.info.as_ref().unwrap().username, "osk");
+    assert_eq!(profile.league_records.as_ref().unwrap().len(), 0);
+    assert!(profile.summaries.is_none());
+    assert!(profile.leagueflow.is_none());
+    assert!(!profile.is_complete());
+
+    match profile.failure(ProfilePart::Summaries) {
+        Some(failure @ PartFailure::Unsuccessful(_)) => assert_eq!(failure.to_string(), "Not found"),
+        other => panic!("expected the summaries to be unsuccessful, got {other:?}"),
+    }
+    assert!(matches!(profile.failure(ProfilePart::LeagueFlow), Some(PartFailure::Request(_))));
+    assert!(profile.failure(ProfilePart::Info).is_none());
+
+    let mut requests = fake.requests().into_iter().map(|request| request.uri).collect::<Vec<_>>();
+    requests.sort();
+    assert_eq!(requests, [
+        "/api/labs/leagueflow/osk",
+        "/api/users/osk",
+        "/api/users/osk/records/league/recent?limit=5",
+        "/api/users/osk/summaries",
+    ]);
+}
+
+#[tokio::test]
+async fn optional_parts_are_skipped() {
+    let fake = profile_client();
+    let client = CachedClient::with_rate_limiter(fake.clone(), NoopCache, LocalRateLimiter::new(Duration::ZERO));
+
+    let profile = client.fetch_profile("osk", ProfileOptions { league_records: None, leagueflow: false }).await;
+
+    assert!(profile.league_records.is_none());
+    assert_eq!(profile.failures.len(), 1);
+    assert_eq!(fake.request_count(), 2);
+    assert_eq!(ProfileOptions::default().league_records, Some(10));
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+use tetrio_api::http::{
+    cached_client::CachedClient,
+    caches::noop_cache::NoopCache,
+    rate_limiters::{local::LocalRateLimiter, rate_limiter::RateLimiter},
+};
+
+use common::FakeClient;
+
+#[derive(Default)]
+struct CountingLimiter(AtomicUsize);
+
+#[async_trait::async_trait]
+impl RateLimiter for CountingLimiter {
+    async fn acquire(&self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn custom_rate_limiter() {
+    let limiter = Arc::new(CountingLimiter::default());
+    let client = CachedClient::with_rate_limiter(FakeClient::always(json!(1)), NoopCache, limiter.clone());
+
+    let start = Instant::now();
+    for _ in 0..3 {
+        client.make_tetrio_api_request::<i64>("general/stats", None).await.unwrap();
+    }
+
+    assert_eq!(limiter.0.load(Ordering::SeqCst), 3);
+    assert!(start.elapsed() < Duration::from_millis(500));
+}
+
+#[tokio::test]
+async fn local_rate_limiter_is_shared() {
+    let limiter = Arc::new(LocalRateLimiter::new(Duration::from_millis(100)));
+    let first = CachedClient::with_rate_limiter(FakeClient::always(json!(1)), NoopCache, limiter.clone());
+    let second = CachedClient::with_rate_limiter(FakeClient::always(json!(1)), NoopCache, limiter.clone());
+
+    let start = Instant::now();
+    for _ in 0..2 {
+        first.make_tetrio_api_request::<i64>("a", None).await.unwrap();
+        second.make_tetrio_api_request::<i64>("b", None).await.unwrap();
+    }
+
+    assert!(start.elapsed() >= Duration::from_millis(300));
+}
+
+#[cfg(feature = "redis_cache")]
+mod redis {
+    use std::time::{Duration, Instant};
+
+    use tetrio_api::http::rate_limiters::{local::LocalRateLimiter, rate_limiter::RateLimiter, redis_rate_limiter::RedisRateLimiter};
+
+    fn redis_url() -> String {
+        std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_string())
+    }
+
+    #[tokio::test]
+    async fn falls_back_when_redis_is_unreachable() {
+        let client = redis::Client::open("redis://127.0.0.1:1/").unwrap();
+        let limiter = RedisRateLimiter::with_bucket(client, "unreachable", 1, Duration::from_secs(10))
+            .with_fallback(LocalRateLimiter::new(Duration::from_millis(100)));
+
+        assert!(limiter.try_acquire().await.is_err());
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(200) && elapsed < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn falls_back_when_redis_is_slow() {
+        // Accepts connections but never answers.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let client = redis::Client::open(format!("redis://{}/", listener.local_addr().unwrap())).unwrap();
+        let accepted = tokio::spawn(async move {
+            let mut sockets = vec![];
+            while let Ok((socket, _)) = listener.accept().await {
+                sockets.push(socket);
+            }
+        });
+
+        let limiter = RedisRateLimiter::with_bucket(client, "slow", 1, Duration::from_secs(10))
+            .with_timeout(Duration::from_millis(100))
+            .with_fallback(LocalRateLimiter::new(Duration::ZERO));
+
+        let start = Instant::now();
+        assert!(limiter.try_acquire().await.is_err());
+        limiter.acquire().await;
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        accepted.abort();
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a local redis instance (REDIS_URL, defaults to redis://127.0.0.1/)"]
+    async fn bucket_is_shared_between_limiters() {
+        let key = format!("tetrio-api:test:{}", std::process::id());
+        let first = RedisRateLimiter::with_bucket(redis::Client::open(redis_url()).unwrap(), &key, 2, Duration::from_millis(200));
+        let second = RedisRateLimiter::with_bucket(redis::Client::open(redis_url()).unwrap(), &key, 2, Duration::from_millis(200));
+
+        // The two tokens of the bucket are available right away.
+        assert_eq!(first.try_acquire().await.unwrap(), None);
+        assert_eq!(second.try_acquire().await.unwrap(), None);
+        assert!(first.try_acquire().await.unwrap().is_some());
+
+        let start = Instant::now();
+        first.acquire().await;
+        second.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(300));
+    }
+}
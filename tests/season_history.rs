@@ -0,0 +1,65 @@
+use serde_json::json;
+use tetrio_api::analysis::season_history::SeasonHistory;
+use tetrio_api::models::season::Season;
+use tetrio_api::models::users::{summaries::tetra_league::LeagueSummary, user_rank::UserRank};
+
+fn past(season: &str, tr: f64, placement: i64, rank: &str) -> serde_json::Value {
+    json!({
+        "season": season, "username": "old_name", "country": "FR", "placement": placement, "ranked": true,
+        "gamesplayed": 100, "gameswon": 60, "glicko": 2000.0, "rd": 60.0, "tr": tr, "gxe": 70.0,
+        "rank": rank, "bestrank": "x", "apm": 80.0, "pps": 2.0, "vs": 170.0
+    })
+}
+
+fn get_summary() -> LeagueSummary {
+    let past = json!({ "10": past("10", 24000.0, 20, "x+"), "1": past("1", 23000.0, 50, "x") });
+
+    serde_json::from_value(json!({
+        "gamesplayed": 40, "gameswon": 10, "glicko": 1500.0, "rd": 80.0, "decaying": false, "tr": 12000.0,
+        "gxe": 50.0, "rank": "b", "bestrank": "a", "apm": 40.0, "pps": 1.5, "vs": 90.0, "standing": -1,
+        "standing_local": -1, "percentile": 0.5, "percentile_rank": "b", "next_rank": "b+", "prev_rank": "b-",
+        "next_at": 10, "prev_at": 20, "past": past
+    }))
+    .unwrap()
+}
+
+#[test]
+fn seasons_are_ordered() {
+    let mut seasons = [Season::new("10"), Season::new("beta"), Season::new("2"), Season::new("1")];
+    seasons.sort();
+
+    assert_eq!(seasons.iter().map(Season::id).collect::<Vec<_>>(), vec!["1", "2", "10", "beta"]);
+    assert_eq!(Season::new("1").name(), "Season 1");
+    assert!(Season::new("1").has_ended());
+    assert_eq!(Season::new("10").name(), "Season 10");
+}
+
+#[test]
+fn numeric_season_ids_are_normalized() {
+    let padded: Season = serde_json::from_value(serde_json::json!("01")).unwrap();
+
+    assert_eq!(padded, Season::new("1"));
+    assert_eq!(padded.cmp(&Season::new("1")), std::cmp::Ordering::Equal);
+    assert_eq!(padded.id(), "1");
+    assert!(padded.has_ended());
+    assert_eq!(std::collections::HashSet::from([padded, Season::from("1")]).len(), 1);
+}
+
+#[test]
+fn season_history() {
+    let history = SeasonHistory::from_summary(&get_summary(), Season::new("11"));
+
+    let seasons = history.entries.iter().map(|entry| entry.season.id()).collect::<Vec<_>>();
+    assert_eq!(seasons, vec!["1", "10", "11"]);
+
+    let current = history.current().unwrap();
+    assert_eq!(current.placement, None);
+    assert!(current.ranked);
+    assert_eq!(current.winrate(), Some(0.25));
+
+    assert_eq!(history.past().count(), 2);
+    assert_eq!(history.best_by_tr().unwrap().season, Season::new("10"));
+    assert_eq!(history.best_placement().unwrap().placement, Some(20));
+    assert_eq!(history.peak_rank(), Some(UserRank::XPlus));
+    assert_eq!(history.total_games(), 240);
+}
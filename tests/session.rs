@@ -0,0 +1,46 @@
+#![cfg(feature = "in_memory_cache")]
+
+mod common;
+
+use std::time::Duration;
+
+use serde_json::json;
+use tetrio_api::http::{
+    cached_client::CachedClient,
+    caches::moka::MokaCache,
+    parameters::{
+        personal_user_records::{PersonalLeaderboard, PersonalRecordsQuery},
+        value_bound_query::ValueBoundQuery,
+    },
+    rate_limiters::local::LocalRateLimiter,
+};
+
+use common::FakeClient;
+
+#[tokio::test]
+async fn session_ids_are_attached_and_scoped() {
+    let fake = FakeClient::always(json!({ "value": 1 }));
+    let client = CachedClient::with_rate_limiter(fake.clone(), MokaCache::default(), LocalRateLimiter::new(Duration::ZERO));
+
+    let session = client.session();
+    let other = client.session();
+    assert_ne!(session.id(), other.id());
+    assert_eq!(client.session_with_id("fixed").id(), "fixed");
+
+    // Paging personal records goes through the session; the response doesn't match the model, only the request matters here.
+    let _ = session.fetch_user_personal_40l_records("osk", PersonalLeaderboard::Top, PersonalRecordsQuery::None).await;
+    let _ = session.fetch_records_leaderboard::<serde_json::Value>("40l_global", ValueBoundQuery::NotBound { limit: Some(10), country: None }).await;
+
+    session.make_tetrio_api_request::<serde_json::Value>("general/stats").await.unwrap();
+    session.make_tetrio_api_request::<serde_json::Value>("general/stats").await.unwrap();
+    client.make_tetrio_api_request::<serde_json::Value>("general/stats", None).await.unwrap();
+
+    let requests = fake.requests().into_iter().map(|request| (request.path, request.session_id)).collect::<Vec<_>>();
+    let id = Some(session.id().to_string());
+    assert_eq!(requests, vec![
+        ("/api/users/osk/records/40l/top".to_string(), id.clone()),
+        ("/api/records/40l_global".to_string(), id.clone()),
+        ("/api/general/stats".to_string(), id),
+        ("/api/general/stats".to_string(), None),
+    ]);
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use serde_json::json;
+use tetrio_api::analysis::solo::{format_time, SoloRun};
+use tetrio_api::models::users::user_records::{BlitzRecord, SprintRecord};
+
+use common::{clears, parse, record, sprint_stats};
+
+fn get_stats(finaltime: f64, pieces: i64, inputs: i64, score: i64, perfect: i64) -> serde_json::Value {
+    let clears = clears(json!({ "singles": 4, "doubles": 2, "quads": 6, "tspinsingles": 1, "tspindoubles": 3, "allclear": 1 }));
+
+    sprint_stats(json!({
+        "level_lines": 5, "level_lines_needed": 20, "inputs": inputs, "score": score, "level": 3,
+        "topcombo": 2, "topbtb": 4, "tspins": 4, "piecesplaced": pieces, "clears": clears,
+        "finesse": { "faults": pieces - perfect, "perfectpieces": perfect },
+        "finaltime": finaltime
+    }))
+}
+
+#[test]
+fn time_formatting() {
+    assert_eq!(format_time(0.0), "00:00.000");
+    assert_eq!(format_time(21_345.4), "00:21.345");
+    assert_eq!(format_time(125_006.0), "02:05.006");
+}
+
+#[test]
+fn sprint_analysis() {
+    let record: SprintRecord = parse(record("40l", get_stats(25_000.0, 100, 300, 5000, 90)));
+    let run = SoloRun::from_sprint_record(&record);
+
+    assert_eq!(run.formatted_time(), "00:25.000");
+    assert_eq!(run.pps(), Some(4.0));
+    assert_eq!(run.kpp(), Some(3.0));
+    assert_eq!(run.kps(), Some(12.0));
+    assert_eq!(run.finesse_rate(), Some(0.9));
+    assert_eq!(run.finesse_faults, Some(10));
+    assert_eq!(run.clears.total_clears(), 16);
+    assert_eq!(run.tspin_share(), Some(0.25));
+    assert_eq!(run.score_per_piece(), Some(50.0));
+    assert_eq!(run.level.progress(), 0.25);
+}
+
+#[test]
+fn run_comparison() {
+    let sprint: SprintRecord = parse(record("40l", get_stats(25_000.0, 100, 300, 5000, 90)));
+    let blitz: BlitzRecord = parse(record("blitz", get_stats(120_000.0, 400, 1000, 100_000, 400)));
+
+    let comparison = SoloRun::from(&sprint).compare(&SoloRun::from(&blitz));
+    let pps = comparison.iter().find(|stat| stat.stat == "pps").unwrap();
+
+    assert_eq!(pps.first, Some(4.0));
+    assert!((pps.delta().unwrap() + 2.0 / 3.0).abs() < 1e-9);
+    assert_eq!(comparison.iter().find(|stat| stat.stat == "finesse").unwrap().second, Some(1.0));
+}
@@ -0,0 +1,43 @@
+use tetrio_api::math::stats::{DerivedStats, VersusStats};
+use tetrio_api::models::users::user_leaderboard::LeaderboardUserLeague;
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-9, "{a} is not close to {b}");
+}
+
+#[test]
+fn derived_stats_formulas() {
+    let stats = DerivedStats::compute(120.0, 2.5, 260.0).unwrap();
+
+    assert_close(stats.app, 0.8);
+    assert_close(stats.ds_second, 0.6);
+    assert_close(stats.ds_piece, 0.24);
+    assert_close(stats.app_ds_piece, 1.04);
+    assert_close(stats.vs_apm, 260.0 / 120.0);
+    assert_close(stats.garbage_efficiency, 0.384);
+    assert!(stats.estimated_tr > 0.0 && stats.estimated_tr < 25000.0);
+}
+
+#[test]
+fn missing_values_give_no_stats() {
+    assert!(DerivedStats::compute(0.0, 2.5, 260.0).is_none());
+    assert!(DerivedStats::compute(120.0, f64::NAN, 260.0).is_none());
+
+    let league: LeaderboardUserLeague = serde_json::from_value(serde_json::json!({
+        "gamesplayed": 10,
+        "gameswon": 5,
+        "tr": 12000.0,
+        "gxe": 48.0,
+        "rank": "a",
+        "bestrank": "a",
+        "glicko": 1480.0,
+        "rd": 70.0,
+        "apm": 40.0,
+        "pps": null,
+        "vs": 80.0,
+        "decaying": false
+    }))
+    .unwrap();
+
+    assert!(league.derived_stats().is_none());
+}
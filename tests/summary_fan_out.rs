@@ -0,0 +1,96 @@
+#![cfg(feature = "in_memory_cache")]
+
+mod common;
+
+use std::time::Duration;
+
+use serde_json::json;
+use tetrio_api::http::{
+    cached_client::CachedClient,
+    caches::moka::MokaCache,
+    rate_limiters::local::LocalRateLimiter,
+};
+
+use common::{hit, success, FakeClient};
+
+fn summaries() -> serde_json::Value {
+    let zenith = json!({ "record": null, "rank": 5, "rank_local": 2, "best": { "record": null, "rank": 3 } });
+
+    json!({
+        "40l": { "record": null, "rank": 10, "rank_local": 1 },
+        "blitz": { "record": null, "rank": 20, "rank_local": 2 },
+        "zenith": zenith,
+        "zenithex": zenith,
+        "league": { "gamesplayed": 12, "gameswon": 7 },
+        "zen": { "level": 30, "score": 1000.0 },
+        "achievements": [],
+    })
+}
+
+fn summaries_client() -> FakeClient {
+    FakeClient::new(|request| match request.path.rsplit('/').next().unwrap() {
+        "summaries" => Ok(success(summaries())),
+        "league" => Ok(success(json!({ "gamesplayed": 99 }))),
+        _ => Err(std::io::Error::other("unexpected request")),
+    })
+}
+
+fn client(fake: &FakeClient) -> CachedClient<FakeClient, MokaCache> {
+    CachedClient::with_rate_limiter(fake.clone(), MokaCache::default(), LocalRateLimiter::new(Duration::ZERO))
+}
+
+#[tokio::test]
+async fn all_summaries_fill_the_per_mode_cache() {
+    let fake = summaries_client();
+    let client = client(&fake);
+
+    let all = client.fetch_user_summaries("osk").await.unwrap();
+    let cached_until = all.cache.as_ref().unwrap().cached_until;
+
+    let league = client.fetch_user_league_summaries("osk").await.unwrap();
+    assert_eq!(league.data.unwrap().gamesplayed, Some(12));
+    assert_eq!(league.cache.unwrap().cached_until, cached_until);
+
+    assert_eq!(client.fetch_user_40l_summaries("osk").await.unwrap().data.unwrap().rank, 10);
+    assert_eq!(client.fetch_user_blitz_summaries("osk").await.unwrap().data.unwrap().rank, 20);
+    assert_eq!(client.fetch_user_zenith_summaries("osk").await.unwrap().data.unwrap().best.rank, 3);
+    assert_eq!(client.fetch_user_zenithex_summaries("osk").await.unwrap().data.unwrap().rank, 5);
+    assert_eq!(client.fetch_user_zen_summaries("osk").await.unwrap().data.unwrap().level, 30);
+    assert!(client.fetch_user_achievements_summaries("osk").await.unwrap().data.unwrap().is_empty());
+
+    // Served from the cache, the summaries are not fanned out a second time.
+    client.fetch_user_summaries("osk").await.unwrap();
+
+    assert_eq!(fake.paths(), ["/api/users/osk/summaries"]);
+}
+
+#[tokio::test]
+async fn per_mode_requests_use_cached_all_summaries() {
+    let fake = summaries_client();
+    let client = client(&fake);
+
+    // Cached without going through fetch_user_summaries, so nothing was fanned out.
+    client.cache_tetrio_api_result_if_not_present::<serde_json::Value>("users/osk/summaries", None, &hit(summaries())).await.unwrap();
+
+    let league = client.fetch_user_league_summaries("osk").await.unwrap();
+    assert_eq!(league.data.unwrap().gamesplayed, Some(12));
+    assert_eq!(fake.request_count(), 0);
+
+    let league = client.fetch_user_league_summaries("zudo").await.unwrap();
+    assert_eq!(league.data.unwrap().gamesplayed, Some(99));
+    assert_eq!(fake.paths(), ["/api/users/zudo/summaries/league"]);
+}
+
+#[tokio::test]
+async fn batched_summaries_fill_the_per_mode_cache() {
+    let fake = summaries_client();
+    let client = client(&fake);
+
+    let results = client.fetch_users_summaries(["osk", "zudo"], |_| {}).await;
+    assert!(results.values().all(|result| result.as_ref().is_ok_and(|packet| packet.is_success())));
+
+    assert_eq!(client.fetch_user_league_summaries("osk").await.unwrap().data.unwrap().gamesplayed, Some(12));
+    assert_eq!(client.fetch_user_40l_summaries("zudo").await.unwrap().data.unwrap().rank, 10);
+
+    assert_eq!(fake.sorted_paths(), ["/api/users/osk/summaries", "/api/users/zudo/summaries"]);
+}
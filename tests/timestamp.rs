@@ -0,0 +1,63 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use serde_json::json;
+use tetrio_api::models::{
+    cache::Cache,
+    news::latest::LatestNews,
+    users::user_badge::{BadgeTimestamp, UserBadge},
+};
+
+#[test]
+fn cache_times() {
+    let cache = Cache { status: "hit".to_string(), cached_at: 1_722_513_600_000, cached_until: 1_722_513_660_000 };
+
+    assert_eq!(cache.cached_at_time(), UNIX_EPOCH + Duration::from_secs(1_722_513_600));
+    assert_eq!(cache.cached_until_time().duration_since(cache.cached_at_time()).unwrap(), Duration::from_secs(60));
+}
+
+fn news(ts: &str) -> serde_json::Value {
+    json!({ "_id": "news", "stream": "global", "type": "leaderboard", "data": {}, "ts": ts })
+}
+
+#[test]
+fn timestamps_round_trip() {
+    let parsed: LatestNews = serde_json::from_value(news("2024-08-01T12:00:00.120Z")).unwrap();
+
+    assert_eq!(serde_json::to_value(&parsed).unwrap()["ts"], "2024-08-01T12:00:00.120Z");
+    assert_eq!(parsed.ts.as_str(), "2024-08-01T12:00:00.120Z");
+    assert_eq!(parsed.ts, "2024-08-01T12:00:00.120Z");
+}
+
+#[test]
+fn hidden_badge_dates_are_kept() {
+    let badge = |ts: serde_json::Value| serde_json::from_value::<UserBadge>(json!({ "id": "kod_founder", "label": "founder", "ts": ts })).unwrap();
+
+    let dated = badge(json!("2024-08-01T12:00:00.000Z"));
+    assert_eq!(dated.ts.as_ref().and_then(BadgeTimestamp::date).unwrap(), "2024-08-01T12:00:00.000Z");
+
+    let hidden = badge(json!(false));
+    assert_eq!(hidden.ts, Some(BadgeTimestamp::Other(json!(false))));
+    assert_eq!(hidden.ts.as_ref().and_then(BadgeTimestamp::date), None);
+    assert_eq!(serde_json::to_value(&hidden).unwrap()["ts"], false);
+
+    assert_eq!(badge(json!(null)).ts, None);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn timestamps_are_typed() {
+    use tetrio_api::models::timestamp::Timestamp;
+
+    let mut items = ["2024-08-02T00:00:00.000Z", "2023-01-01T00:00:00.000Z", "2024-08-01T12:00:00.000Z"]
+        .map(|ts| serde_json::from_value::<LatestNews>(news(ts)).unwrap());
+    items.sort_by(|left, right| left.ts.cmp(&right.ts));
+
+    assert_eq!(items[0].ts.to_string(), "2023-01-01T00:00:00.000Z");
+    assert_eq!(items[2].ts.millis(), Some(1_722_556_800_000));
+    assert!(items[0].ts.days_ago().unwrap() > 365);
+    assert_eq!(Timestamp::new("not a date").datetime(), None);
+
+    let cache = Cache { status: "hit".to_string(), cached_at: 1_722_556_800_000, cached_until: 1_722_556_860_000 };
+    assert_eq!(cache.cached_at_timestamp(), Some(items[2].ts.clone()));
+    assert!(Timestamp::from_millis(0).unwrap() < items[0].ts);
+}
@@ -0,0 +1,29 @@
+use tetrio_api::models::users::user_rank::UserRank;
+use tetrio_api::urls::Urls;
+
+#[test]
+fn default_hosts() {
+    let urls = Urls::default();
+
+    assert_eq!(urls.avatar("5e32fc85ab319c2ab1beb07c", Some(1704054856563)), "https://tetr.io/user-content/avatars/5e32fc85ab319c2ab1beb07c.jpg?rv=1704054856563");
+    assert_eq!(urls.avatar("5e32fc85ab319c2ab1beb07c", None), "https://tetr.io/res/avatar.png");
+    assert_eq!(urls.avatar("5e32fc85ab319c2ab1beb07c", Some(0)), "https://tetr.io/res/avatar.png");
+    assert_eq!(urls.banner("5e32fc85ab319c2ab1beb07c", None), None);
+    assert_eq!(urls.banner("id", Some(12)).as_deref(), Some("https://tetr.io/user-content/banners/id.jpg?rv=12"));
+    assert_eq!(urls.badge_icon("leaderboard1"), "https://tetr.io/res/badges/leaderboard1.png");
+    assert_eq!(urls.country_flag("FR"), "https://tetr.io/res/flags/fr.png");
+    assert_eq!(urls.rank_icon(&UserRank::XPlus), "https://tetr.io/res/league-ranks/x+.png");
+    assert_eq!(UserRank::XPlus.icon_url(), "https://tetr.io/res/league-ranks/x+.png");
+    assert_eq!(urls.profile("OSK"), "https://ch.tetr.io/u/osk");
+    assert_eq!(urls.replay("abc"), "https://tetr.io/#R:abc");
+    assert_eq!(urls.record("def"), "https://tetr.io/#r:def");
+}
+
+#[test]
+fn custom_hosts() {
+    let urls = Urls::default().with_tetrio_host("https://proxy.example/").with_channel_host("https://channel.example");
+
+    assert_eq!(urls.badge_icon("kod_founder"), "https://proxy.example/res/badges/kod_founder.png");
+    assert_eq!(urls.rank_icon(&UserRank::SPlus), "https://proxy.example/res/league-ranks/s+.png");
+    assert_eq!(urls.profile("osk"), "https://channel.example/u/osk");
+}
@@ -0,0 +1,42 @@
+use std::str::FromStr;
+
+use tetrio_api::models::users::user_rank::UserRank;
+
+#[test]
+fn ranks_are_ordered() {
+    assert!(UserRank::XPlus > UserRank::X);
+    assert!(UserRank::SS > UserRank::SPlus);
+    assert!(UserRank::DPlus > UserRank::D);
+    assert!(UserRank::D > UserRank::Z);
+    assert!(UserRank::Z > UserRank::Unknown("?".to_string()));
+
+    let mut ranks = vec![UserRank::A, UserRank::Z, UserRank::U, UserRank::CMinus];
+    ranks.sort();
+    assert_eq!(ranks, vec![UserRank::Z, UserRank::CMinus, UserRank::A, UserRank::U]);
+}
+
+#[test]
+fn ranks_parse_case_insensitively() {
+    assert_eq!(UserRank::from_str("s+"), Ok(UserRank::SPlus));
+    assert_eq!(UserRank::from_str("X+"), Ok(UserRank::XPlus));
+    assert_eq!(UserRank::from_str(" ss "), Ok(UserRank::SS));
+    assert_eq!(UserRank::from_str("Z"), Ok(UserRank::Z));
+    assert!(UserRank::from_str("e").is_err());
+}
+
+#[test]
+fn ranks_have_neighbours() {
+    assert_eq!(UserRank::D.previous_rank(), None);
+    assert_eq!(UserRank::D.next_rank(), Some(UserRank::DPlus));
+    assert_eq!(UserRank::SS.next_rank(), Some(UserRank::U));
+    assert_eq!(UserRank::XPlus.next_rank(), None);
+    assert_eq!(UserRank::Z.next_rank(), None);
+    assert_eq!(UserRank::iter().count(), 18);
+}
+
+#[test]
+fn ranks_have_metadata() {
+    assert_eq!(UserRank::X.color_hex().as_deref(), Some("#FF45FF"));
+    assert_eq!(UserRank::Unknown("?".to_string()).color(), None);
+    assert_eq!(UserRank::SPlus.icon_url(), "https://tetr.io/res/league-ranks/s+.png");
+}
@@ -0,0 +1,147 @@
+mod common;
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use std::time::Duration;
+
+use futures::StreamExt;
+use serde_json::json;
+use tetrio_api::http::{
+    cached_client::CachedClient,
+    caches::noop_cache::NoopCache,
+    parameters::personal_user_records::GameMode,
+    profile::PartFailure,
+    rate_limiters::local::LocalRateLimiter,
+    watch::{UserEvent, UserSnapshot, WatchOptions},
+};
+use tetrio_api::models::users::user_rank::UserRank;
+
+use common::{failure, sprint_record, success_for, user_info, FakeClient};
+
+fn info(username: &str, country: Option<&str>, role: &str, badges: &[&str]) -> serde_json::Value {
+    let badges = badges.iter().map(|id| json!({ "id": id, "label": id, "ts": null })).collect::<Vec<_>>();
+
+    user_info(username, json!({ "country": country, "role": role, "badges": badges }))
+}
+
+fn summaries(record: Option<&str>, rank: &str, tr: f64, played: i64, won: i64) -> serde_json::Value {
+    let zenith = json!({ "record": null, "rank": 5, "rank_local": 2, "best": { "record": null, "rank": 3 } });
+
+    json!({
+        "40l": { "record": record.map(sprint_record), "rank": 10, "rank_local": 1 },
+        "blitz": { "record": null, "rank": 20, "rank_local": 2 },
+        "zenith": zenith,
+        "zenithex": zenith,
+        "league": { "gamesplayed": played, "gameswon": won, "tr": tr, "rank": rank },
+        "zen": { "level": 30, "score": 1000.0 },
+        "achievements": [],
+    })
+}
+
+fn snapshot(info: serde_json::Value, summaries: serde_json::Value) -> UserSnapshot {
+    UserSnapshot {
+        info: serde_json::from_value(info).unwrap(),
+        summaries: serde_json::from_value(summaries).unwrap(),
+    }
+}
+
+#[test]
+fn snapshots_are_diffed() {
+    let before = snapshot(info("osk", Some("JP"), "user", &["early"]), summaries(None, "a", 20000.0, 10, 5));
+    let after = snapshot(info("osk2", None, "mod", &["early", "leaderboard1"]), summaries(Some("pb"), "a+", 20015.0, 13, 7));
+
+    let events = before.diff(&after, 10.0);
+    assert_eq!(events.len(), 8);
+    assert_eq!(events[0], UserEvent::UsernameChanged { previous: "osk".to_string(), current: "osk2".to_string() });
+    assert_eq!(events[1], UserEvent::CountryChanged { previous: Some("JP".to_string()), current: None });
+    assert!(matches!(events[2], UserEvent::RoleChanged { .. }));
+    assert!(matches!(&events[3], UserEvent::BadgesGained { badges } if badges.len() == 1 && badges[0].id == "leaderboard1"));
+    assert_eq!(events[4], UserEvent::PersonalBest {
+        mode: GameMode::Sprint,
+        record_id: "pb".to_string(),
+        replay_id: "replay-pb".to_string(),
+        previous_record_id: None,
+    });
+    assert_eq!(events[5], UserEvent::RankUp { previous: UserRank::A, current: UserRank::APlus });
+    assert_eq!(events[6], UserEvent::TrChanged { previous: 20000.0, current: 20015.0 });
+    assert_eq!(events[7], UserEvent::LeagueMatches { played: 3, won: 2 });
+
+    assert!(after.diff(&after, 10.0).is_empty());
+    assert!(before.diff(&snapshot(info("osk", Some("JP"), "user", &["early"]), summaries(None, "a", 20009.0, 10, 5)), 10.0).is_empty());
+    assert!(after.diff(&before, 10.0).contains(&UserEvent::RankDown { previous: UserRank::APlus, current: UserRank::A }));
+
+    // Measured from the last reported TR, small changes add up.
+    let drifted = snapshot(info("osk", Some("JP"), "user", &["early"]), summaries(None, "a", 20009.0, 10, 5));
+    assert_eq!(before.diff_since(&drifted, 10.0, Some(19995.0)), [UserEvent::TrChanged { previous: 19995.0, current: 20009.0 }]);
+}
+
+/// The user plays a match between the first and the second poll, the responses expire after 200ms.
+fn changing_client() -> FakeClient {
+    let requests = AtomicUsize::new(0);
+
+    FakeClient::new(move |request| {
+        let poll = requests.fetch_add(1, Ordering::SeqCst) / 2;
+        let data = match (request.path.as_str(), poll) {
+            ("/api/users/ghost", _) | ("/api/users/ghost/summaries", _) => return Ok(failure("No such user!")),
+            ("/api/users/osk", _) => info("osk", Some("JP"), "user", &[]),
+            (_, 0) => summaries(None, "a", 20000.0, 10, 5),
+            _ => summaries(None, "a", 20000.0, 11, 6),
+        };
+
+        Ok(success_for(data, Duration::from_millis(200)))
+    })
+}
+
+#[tokio::test(start_paused = true)]
+async fn watching_waits_for_the_cache_to_expire() {
+    let fake = changing_client();
+    let client = CachedClient::with_rate_limiter(fake.clone(), NoopCache, LocalRateLimiter::new(Duration::ZERO));
+
+    let start = tokio::time::Instant::now();
+    let mut events = Box::pin(client.watch_user("osk", Duration::from_millis(10)));
+    let event = tokio::time::timeout(Duration::from_secs(2), events.next()).await.unwrap().unwrap();
+
+    assert!(matches!(event, Ok(UserEvent::LeagueMatches { played: 1, won: 1 })));
+    assert!(start.elapsed() >= Duration::from_millis(180));
+    assert_eq!(fake.request_count(), 4);
+}
+
+#[tokio::test(start_paused = true)]
+async fn failed_polls_are_reported() {
+    let client = CachedClient::with_rate_limiter(changing_client(), NoopCache, LocalRateLimiter::new(Duration::ZERO));
+
+    let mut events = Box::pin(client.watch_user_with("ghost", WatchOptions::new(Duration::from_millis(10)).with_tr_threshold(1.0)));
+    let failure = events.next().await.unwrap().unwrap_err();
+
+    assert!(matches!(failure, PartFailure::Unsuccessful(Some(ref msg)) if msg == "No such user!"));
+    assert!(events.next().await.unwrap().is_err());
+}
+
+/// Each poll, the TR moves by less than the threshold.
+fn drifting_client(polls: Arc<AtomicUsize>) -> FakeClient {
+    FakeClient::new(move |request| {
+        let data = match request.path.as_str() {
+            "/api/users/osk" => info("osk", Some("JP"), "user", &[]),
+            _ => summaries(None, "a", 20000.0 + 4.0 * polls.fetch_add(1, Ordering::SeqCst) as f64, 10, 5),
+        };
+
+        Ok(success_for(data, Duration::ZERO))
+    })
+}
+
+#[tokio::test(start_paused = true)]
+async fn slow_tr_drift_is_reported() {
+    let polls = Arc::new(AtomicUsize::new(0));
+    let client = CachedClient::with_rate_limiter(drifting_client(polls.clone()), NoopCache, LocalRateLimiter::new(Duration::ZERO));
+
+    let mut events = Box::pin(client.watch_user_with("osk", WatchOptions::new(Duration::from_millis(10)).with_tr_threshold(10.0)));
+    let event = tokio::time::timeout(Duration::from_secs(2), events.next()).await.unwrap().unwrap();
+    assert_eq!(event.unwrap(), UserEvent::TrChanged { previous: 20000.0, current: 20012.0 });
+    assert_eq!(polls.load(Ordering::SeqCst), 4);
+
+    // The next change is measured from the reported TR.
+    let event = tokio::time::timeout(Duration::from_secs(2), events.next()).await.unwrap().unwrap();
+    assert_eq!(event.unwrap(), UserEvent::TrChanged { previous: 20012.0, current: 20024.0 });
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use serde_json::json;
+use tetrio_api::analysis::zenith::ZenithRun;
+use tetrio_api::models::users::user_records::{zenith_record::ZenithMod, ZenithRecord};
+
+use common::{clears, merge, parse, record};
+
+fn get_record(splits: [i64; 10], altitude: f64, floor: i64, finaltime: f64, mods: &[&str]) -> ZenithRecord {
+    let zenith = json!({
+        "altitude": altitude, "rank": 1.0, "peakrank": 5.0, "avgrankpts": 0.0, "floor": floor,
+        "targetingfactor": 0.0, "targetinggrace": 0.0, "totalbonus": 0.0, "revives": 1,
+        "revivesTotal": 1, "speedrun": false, "speedrun_seen": true, "splits": splits
+    });
+    let stats = json!({
+        "lines": 0, "level_lines": 0, "level_lines_needed": 0, "inputs": 0, "score": 0,
+        "level": 1, "combo": 0, "topcombo": 0, "btb": 0, "topbtb": 0, "tspins": 0,
+        "piecesplaced": 0, "clears": clears(json!({})),
+        "garbage": { "sent": 0, "received": 0 },
+        "zenith": zenith,
+        "finaltime": finaltime
+    });
+
+    parse(merge(record("zenith", stats), json!({
+        "results": { "aggregatestats": { "apm": 60.0, "pps": 2.0, "vsscore": 120.0 }, "gameoverreason": "topout" },
+        "extras": { "zenith": { "mods": mods } }
+    })))
+}
+
+#[test]
+fn mods_are_typed() {
+    let record = get_record([0; 10], 10.0, 1, 1000.0, &["expert", "nohold_reversed", "newmod"]);
+    let mods = &record.extras.zenith.mods;
+
+    assert_eq!(mods[0], ZenithMod::Expert);
+    assert_eq!(mods[1], ZenithMod::NoHoldReversed);
+    assert!(mods[1].is_reversed());
+    assert_eq!(mods[1].base(), ZenithMod::NoHold);
+    assert_eq!(mods[2], ZenithMod::Unknown("newmod".to_string()));
+}
+
+#[test]
+fn run_analysis() {
+    let record = get_record([30000, 70000, 0, 0, 0, 0, 0, 0, 0, 0], 200.0, 3, 100000.0, &["allspin"]);
+    let run = ZenithRun::from_record(&record).unwrap();
+
+    assert_eq!(run.floor.name, "The Casino");
+    assert_eq!(run.splits.len(), 2);
+    assert_eq!(run.climb_speed(), Some(2.0));
+    assert!(run.used_revives());
+    assert!(!run.has_reversed_mods());
+
+    let times = run.time_per_floor().into_iter().map(|(floor, time)| (floor.number, time)).collect::<Vec<_>>();
+    assert_eq!(times, vec![(1, 30000), (2, 40000), (3, 30000)]);
+}
+
+#[test]
+fn run_comparison() {
+    let first = ZenithRun::from_record(&get_record([30000, 70000, 0, 0, 0, 0, 0, 0, 0, 0], 200.0, 3, 100000.0, &[])).unwrap();
+    let second = ZenithRun::from_record(&get_record([25000, 0, 0, 0, 0, 0, 0, 0, 0, 0], 100.0, 2, 50000.0, &[])).unwrap();
+
+    let comparison = first.compare(&second);
+    assert_eq!(comparison.len(), 2);
+    assert_eq!(comparison[0].delta(), Some(-5000));
+    assert_eq!(comparison[1].second, None);
+    assert_eq!(comparison[1].delta(), None);
+}